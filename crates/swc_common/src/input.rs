@@ -38,6 +38,12 @@ impl<'a> StringInput<'a> {
             orig_start: start,
         }
     }
+
+    /// Returns the part of the input which is not consumed yet.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.iter.as_str()
+    }
 }
 
 /// Creates an [Input] from [SourceFile]. This is an alias for
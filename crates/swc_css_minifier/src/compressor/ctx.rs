@@ -13,11 +13,14 @@ pub(super) struct Ctx {
     pub in_keyframe_block: bool,
 
     pub preserve_alpha_value: bool,
+
+    pub preserve_length_unit: bool,
 }
 impl Default for Ctx {
     fn default() -> Self {
         Self {
             preserve_alpha_value: true,
+            preserve_length_unit: false,
             in_math_function: false,
             in_logic_combinator_selector: false,
             in_transform_function: false,
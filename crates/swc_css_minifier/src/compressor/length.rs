@@ -118,7 +118,7 @@ impl Compressor {
     }
 
     pub(super) fn compress_component_value_for_length(&mut self, n: &mut ComponentValue) {
-        if self.ctx.in_math_function {
+        if self.ctx.in_math_function || self.ctx.preserve_length_unit {
             return;
         }
 
@@ -110,6 +110,14 @@ impl VisitMut for Compressor {
                         ..self.ctx
                     }));
                 }
+                // `flex: 0` is `flex: 0 1 0%`, but `flex: 0px` is `flex: 1 1 0px`, so a unitless
+                // zero is a flex factor here
+                name if matches!(&*name, "flex" | "-webkit-flex" | "-ms-flex") => {
+                    n.visit_mut_children_with(&mut *self.with_ctx(Ctx {
+                        preserve_length_unit: true,
+                        ..self.ctx
+                    }));
+                }
                 _ => {
                     n.visit_mut_children_with(self);
                 }
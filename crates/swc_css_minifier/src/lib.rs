@@ -11,6 +11,7 @@ use self::{compressor::compressor, options::MinifyOptions};
 
 mod compressor;
 pub mod options;
+pub mod passes;
 pub(crate) mod util;
 
 pub fn minify(stylesheet: &mut Stylesheet, _options: MinifyOptions) {
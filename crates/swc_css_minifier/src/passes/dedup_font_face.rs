@@ -0,0 +1,99 @@
+use swc_common::EqIgnoreSpan;
+use swc_css_ast::*;
+use swc_css_visit::{VisitMut, VisitMutWith};
+
+/// Descriptors which identify a font face, `unicode-range` is included because
+/// subsets of the same font share all other descriptors.
+const KEY_DESCRIPTORS: [&str; 4] = ["font-family", "font-style", "font-weight", "unicode-range"];
+
+/// Removes `@font-face` rules which have the same `font-family`,
+/// `font-style`, `font-weight` and `unicode-range` descriptors as a later
+/// `@font-face` rule in the same block.
+///
+/// The last rule is kept, because it's the one browsers use for the font.
+///
+/// Rules without `font-family` are ignored.
+#[derive(Debug, Default)]
+pub struct DedupFontFace;
+
+impl VisitMut for DedupFontFace {
+    fn visit_mut_stylesheet(&mut self, n: &mut Stylesheet) {
+        n.visit_mut_children_with(self);
+
+        dedup(&mut n.rules, |rule| match rule {
+            Rule::AtRule(at_rule) => Some(&**at_rule),
+            _ => None,
+        });
+    }
+
+    fn visit_mut_simple_block(&mut self, n: &mut SimpleBlock) {
+        n.visit_mut_children_with(self);
+
+        dedup(&mut n.value, |component_value| match component_value {
+            ComponentValue::Rule(Rule::AtRule(at_rule))
+            | ComponentValue::DeclarationOrAtRule(DeclarationOrAtRule::AtRule(at_rule)) => {
+                Some(&**at_rule)
+            }
+            _ => None,
+        });
+    }
+}
+
+type Key = Vec<Option<Vec<ComponentValue>>>;
+
+fn dedup<T, F>(nodes: &mut Vec<T>, get_at_rule: F)
+where
+    F: Fn(&T) -> Option<&AtRule>,
+{
+    let mut keys: Vec<Key> = vec![];
+    let mut is_overridden = vec![false; nodes.len()];
+
+    // Walk backwards, because the last rule is the one used by browsers
+    for (index, node) in nodes.iter().enumerate().rev() {
+        let key = match get_at_rule(node).and_then(get_key) {
+            Some(key) => key,
+            _ => continue,
+        };
+
+        if keys.iter().any(|other| other.eq_ignore_span(&key)) {
+            is_overridden[index] = true;
+
+            continue;
+        }
+
+        keys.push(key);
+    }
+
+    let mut is_overridden = is_overridden.into_iter();
+
+    nodes.retain(|_| !is_overridden.next().unwrap());
+}
+
+fn get_key(at_rule: &AtRule) -> Option<Key> {
+    match &at_rule.name {
+        AtRuleName::Ident(Ident { value, .. }) if value.eq_ignore_ascii_case("font-face") => {}
+        _ => return None,
+    }
+
+    let mut key: Key = vec![None; KEY_DESCRIPTORS.len()];
+
+    for component_value in &at_rule.block.as_ref()?.value {
+        if let ComponentValue::DeclarationOrAtRule(DeclarationOrAtRule::Declaration(declaration)) =
+            component_value
+        {
+            if let DeclarationName::Ident(Ident { value, .. }) = &declaration.name {
+                if let Some(index) = KEY_DESCRIPTORS
+                    .iter()
+                    .position(|name| value.eq_ignore_ascii_case(name))
+                {
+                    // The last declaration wins
+                    key[index] = Some(declaration.value.clone());
+                }
+            }
+        }
+    }
+
+    key[0].as_ref()?;
+
+    Some(key)
+}
@@ -0,0 +1,164 @@
+use swc_common::DUMMY_SP;
+use swc_css_ast::*;
+use swc_css_visit::{VisitMut, VisitMutWith};
+
+use crate::is_css_wide_keyword;
+
+/// Longhands in the order they are written in the shorthand.
+const LONGHANDS: [&str; 7] = [
+    "background-color",
+    "background-image",
+    "background-position",
+    "background-size",
+    "background-repeat",
+    "background-attachment",
+    "background-origin",
+];
+
+/// Replaces `background-color`, `background-image`, `background-position`,
+/// `background-size`, `background-repeat`, `background-attachment` and
+/// `background-origin` with a single `background` shorthand when all of them
+/// are declared in the same block, the shorthand is placed where the first
+/// longhand was.
+///
+/// Blocks are left untouched when a longhand is declared more than once, when
+/// any other `background` property is declared, or when a value is
+/// `!important`, a CSS-wide keyword, a list of layers or depends on `var()` or
+/// `env()`.
+#[derive(Debug, Default)]
+pub struct MergeBackgroundLonghands;
+
+impl VisitMut for MergeBackgroundLonghands {
+    fn visit_mut_simple_block(&mut self, n: &mut SimpleBlock) {
+        n.visit_mut_children_with(self);
+
+        let indexes = match find_longhands(&n.value) {
+            Some(indexes) => indexes,
+            None => return,
+        };
+
+        let mut value = vec![];
+
+        for (longhand, index) in LONGHANDS.iter().zip(indexes.iter()) {
+            let declaration = get_declaration(&n.value[*index]).unwrap();
+
+            if *longhand == "background-size" {
+                value.push(ComponentValue::Delimiter(Delimiter {
+                    span: DUMMY_SP,
+                    value: DelimiterValue::Solidus,
+                }));
+            }
+
+            value.extend(declaration.value.iter().cloned());
+
+            // A single box in the shorthand sets both `background-origin` and
+            // `background-clip`, so keep `background-clip` at its initial value
+            if *longhand == "background-origin" && !is_border_box(&declaration.value) {
+                value.push(ComponentValue::Ident(Ident {
+                    span: DUMMY_SP,
+                    value: "border-box".into(),
+                    raw: None,
+                }));
+            }
+        }
+
+        let first = *indexes.iter().min().unwrap();
+
+        if let Some(declaration) = get_declaration_mut(&mut n.value[first]) {
+            declaration.name = DeclarationName::Ident(Ident {
+                span: DUMMY_SP,
+                value: "background".into(),
+                raw: None,
+            });
+            declaration.value = value;
+        }
+
+        let mut index = 0;
+
+        n.value.retain(|_| {
+            let keep = index == first || !indexes.contains(&index);
+
+            index += 1;
+
+            keep
+        });
+    }
+}
+
+fn find_longhands(nodes: &[ComponentValue]) -> Option<[usize; 7]> {
+    let mut indexes = [None; 7];
+
+    for (index, node) in nodes.iter().enumerate() {
+        let declaration = match get_declaration(node) {
+            Some(declaration) => declaration,
+            _ => continue,
+        };
+
+        let name = match &declaration.name {
+            DeclarationName::Ident(Ident { value, .. }) => value.to_ascii_lowercase(),
+            _ => continue,
+        };
+
+        if !name.starts_with("background") {
+            continue;
+        }
+
+        let position = LONGHANDS.iter().position(|longhand| *longhand == &*name)?;
+
+        if indexes[position].is_some() || !can_be_merged(declaration) {
+            return None;
+        }
+
+        indexes[position] = Some(index);
+    }
+
+    let mut result = [0; 7];
+
+    for (slot, index) in result.iter_mut().zip(indexes.iter()) {
+        *slot = (*index)?;
+    }
+
+    Some(result)
+}
+
+fn can_be_merged(declaration: &Declaration) -> bool {
+    if declaration.important.is_some() || declaration.value.is_empty() {
+        return false;
+    }
+
+    declaration.value.iter().all(|value| match value {
+        ComponentValue::Ident(Ident { value, .. }) => !is_css_wide_keyword(value),
+        ComponentValue::Delimiter(Delimiter {
+            value: DelimiterValue::Comma,
+            ..
+        }) => false,
+        ComponentValue::Function(Function { name, .. }) => {
+            !name.value.eq_ignore_ascii_case("var") && !name.value.eq_ignore_ascii_case("env")
+        }
+        _ => true,
+    })
+}
+
+fn is_border_box(value: &[ComponentValue]) -> bool {
+    matches!(value, [ComponentValue::Ident(Ident { value, .. })] if value.eq_ignore_ascii_case("border-box"))
+}
+
+fn get_declaration(node: &ComponentValue) -> Option<&Declaration> {
+    match node {
+        ComponentValue::StyleBlock(StyleBlock::Declaration(declaration))
+        | ComponentValue::DeclarationOrAtRule(DeclarationOrAtRule::Declaration(declaration)) => {
+            Some(declaration)
+        }
+        _ => None,
+    }
+}
+
+fn get_declaration_mut(node: &mut ComponentValue) -> Option<&mut Declaration> {
+    match node {
+        ComponentValue::StyleBlock(StyleBlock::Declaration(declaration))
+        | ComponentValue::DeclarationOrAtRule(DeclarationOrAtRule::Declaration(declaration)) => {
+            Some(declaration)
+        }
+        _ => None,
+    }
+}
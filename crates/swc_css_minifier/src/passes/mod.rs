@@ -0,0 +1,2 @@
+pub mod dedup_font_face;
+pub mod merge_background_longhands;
@@ -5,12 +5,20 @@ use swc_css_codegen::{
     writer::basic::{BasicCssWriter, BasicCssWriterConfig},
     CodeGenerator, CodegenConfig, Emit,
 };
-use swc_css_minifier::minify;
+use swc_css_minifier::{
+    minify,
+    passes::{
+        dedup_font_face::DedupFontFace, merge_background_longhands::MergeBackgroundLonghands,
+    },
+};
 use swc_css_parser::parse_file;
+use swc_css_visit::VisitMutWith;
 use testing::NormalizedOutput;
 
-#[testing::fixture("tests/fixture/**/input.css")]
-fn minify_fixtures(input: PathBuf) {
+fn run_fixture<F>(input: PathBuf, transform: F)
+where
+    F: FnOnce(&mut Stylesheet),
+{
     let dir = input.parent().unwrap();
     let output = dir.join(format!(
         "output.min.{}",
@@ -34,7 +42,7 @@ fn minify_fixtures(input: PathBuf) {
         let mut ss = res.unwrap();
 
         // Apply transforms
-        minify(&mut ss, Default::default());
+        transform(&mut ss);
 
         let mut css_str = String::new();
         {
@@ -52,3 +60,18 @@ fn minify_fixtures(input: PathBuf) {
     })
     .unwrap();
 }
+
+#[testing::fixture("tests/fixture/**/input.css")]
+fn minify_fixtures(input: PathBuf) {
+    run_fixture(input, |ss| minify(ss, Default::default()));
+}
+
+#[testing::fixture("tests/passes/dedup_font_face/**/input.css")]
+fn dedup_font_face_fixtures(input: PathBuf) {
+    run_fixture(input, |ss| ss.visit_mut_with(&mut DedupFontFace));
+}
+
+#[testing::fixture("tests/passes/merge_background_longhands/**/input.css")]
+fn merge_background_longhands_fixtures(input: PathBuf) {
+    run_fixture(input, |ss| ss.visit_mut_with(&mut MergeBackgroundLonghands));
+}
@@ -59,6 +59,29 @@ where
     res
 }
 
+/// Parse a given string as `T`, where `src` is a part of a bigger file and
+/// starts at `offset`, i.e. the value of a `style` attribute in a HTML
+/// document.
+///
+/// All spans, including spans of errors, are shifted by `offset`, so they point
+/// to the original file.
+///
+/// If there are syntax errors but if it was recoverable, it will be appended
+/// to `errors`.
+pub fn parse_str_with_offset<'a, T>(
+    src: &'a str,
+    offset: BytePos,
+    config: ParserConfig,
+    errors: &mut Vec<Error>,
+) -> PResult<T>
+where
+    Parser<Lexer<StringInput<'a>>>: Parse<T>,
+{
+    let end_pos = offset + BytePos(src.len() as u32);
+
+    parse_str(src, offset, end_pos, config, errors)
+}
+
 /// Parse a given file as `T`.
 ///
 /// If there are syntax errors but if it was recoverable, it will be appended
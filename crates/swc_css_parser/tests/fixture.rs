@@ -3,7 +3,7 @@
 
 use std::path::PathBuf;
 
-use swc_common::{errors::Handler, input::SourceFileInput, Span, Spanned};
+use swc_common::{errors::Handler, input::SourceFileInput, BytePos, Span, Spanned};
 use swc_css_ast::*;
 use swc_css_parser::{
     lexer::Lexer,
@@ -599,3 +599,24 @@ fn recovery(input: PathBuf) {
 fn span_visualizer(input: PathBuf) {
     stylesheet_span_visualizer(input, None)
 }
+
+#[test]
+fn parse_str_with_offset() {
+    let src = "color: red; width: 10px";
+    let mut errors = vec![];
+
+    let declarations: Vec<DeclarationOrAtRule> =
+        swc_css_parser::parse_str_with_offset(src, BytePos(42), Default::default(), &mut errors)
+            .unwrap();
+
+    assert!(errors.is_empty());
+    assert_eq!(declarations.len(), 2);
+    assert_eq!(
+        declarations[0].span(),
+        Span::new(BytePos(42), BytePos(52), Default::default())
+    );
+    assert_eq!(
+        declarations[1].span(),
+        Span::new(BytePos(54), BytePos(65), Default::default())
+    );
+}
@@ -8,6 +8,12 @@ use serde::Deserialize;
 pub struct Options {
     #[serde(default = "default_targets")]
     pub env: Option<Targets>,
+
+    /// Expand `place-content`, `place-items` and `place-self` into the legacy
+    /// `-ms-flex-*` properties for IE 10/11, which don't support the
+    /// shorthands.
+    #[serde(default)]
+    pub expand_place_shorthands: bool,
 }
 
 fn default_targets() -> Option<Targets> {
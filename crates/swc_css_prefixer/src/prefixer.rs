@@ -114,6 +114,7 @@ pub fn prefixer(options: Options) -> impl VisitMut {
 
     Prefixer {
         env,
+        expand_place_shorthands: options.expand_place_shorthands,
         ..Default::default()
     }
 }
@@ -518,6 +519,107 @@ where
     node.visit_mut_with(&mut MediaFeatureResolutionReplacerOnLegacyVariant { from, to });
 }
 
+pub struct MediaFeatureNameReplacerOnLegacyVariant<'a> {
+    from: &'a str,
+    to: &'a str,
+}
+
+impl MediaFeatureNameReplacerOnLegacyVariant<'_> {
+    fn replace(&self, n: &mut MediaFeatureName) {
+        let MediaFeatureName::Ident(Ident { value, span, .. }) = n;
+
+        if &*value.to_lowercase() == self.from {
+            *n = MediaFeatureName::Ident(Ident {
+                span: *span,
+                value: self.to.into(),
+                raw: None,
+            });
+        }
+    }
+}
+
+impl VisitMut for MediaFeatureNameReplacerOnLegacyVariant<'_> {
+    fn visit_mut_media_feature_plain(&mut self, n: &mut MediaFeaturePlain) {
+        n.visit_mut_children_with(self);
+
+        self.replace(&mut n.name);
+    }
+
+    fn visit_mut_media_feature_boolean(&mut self, n: &mut MediaFeatureBoolean) {
+        n.visit_mut_children_with(self);
+
+        self.replace(&mut n.name);
+    }
+}
+
+pub fn replace_media_feature_name_on_legacy_variant<N>(node: &mut N, from: &str, to: &str)
+where
+    N: for<'aa> VisitMutWith<MediaFeatureNameReplacerOnLegacyVariant<'aa>>,
+{
+    node.visit_mut_with(&mut MediaFeatureNameReplacerOnLegacyVariant { from, to });
+}
+
+/// Replaces `repeat(N, <track-list>)` with N copies of the track list, i.e.
+/// `repeat(2, 1fr 10px)` becomes `1fr 10px 1fr 10px`.
+///
+/// `repeat()` with `auto-fill` or `auto-fit` can't be expanded, it is left as
+/// is and `has_unexpandable` is set.
+#[derive(Debug, Default)]
+pub struct RepeatExpander {
+    pub has_unexpandable: bool,
+}
+
+impl RepeatExpander {
+    fn expand(function: &Function) -> Option<Vec<ComponentValue>> {
+        match &*function.value {
+            [ComponentValue::Integer(Integer { value, .. }), ComponentValue::Delimiter(Delimiter {
+                value: DelimiterValue::Comma,
+                ..
+            }), tracks @ ..]
+                if *value > 0 && !tracks.is_empty() =>
+            {
+                Some(
+                    tracks
+                        .iter()
+                        .cycle()
+                        .take(*value as usize * tracks.len())
+                        .cloned()
+                        .collect(),
+                )
+            }
+            _ => None,
+        }
+    }
+}
+
+impl VisitMut for RepeatExpander {
+    fn visit_mut_component_values(&mut self, n: &mut Vec<ComponentValue>) {
+        n.visit_mut_children_with(self);
+
+        let mut expanded = Vec::with_capacity(n.len());
+
+        for component_value in take(n) {
+            match component_value {
+                ComponentValue::Function(function)
+                    if function.name.value.eq_ignore_ascii_case("repeat") =>
+                {
+                    match Self::expand(&function) {
+                        Some(tracks) => expanded.extend(tracks),
+                        None => {
+                            self.has_unexpandable = true;
+
+                            expanded.push(ComponentValue::Function(function));
+                        }
+                    }
+                }
+                _ => expanded.push(component_value),
+            }
+        }
+
+        *n = expanded;
+    }
+}
+
 macro_rules! to_ident {
     ($val:expr) => {{
         ComponentValue::Ident(Ident {
@@ -538,6 +640,115 @@ macro_rules! to_integer {
     }};
 }
 
+fn to_zero_px() -> ComponentValue {
+    ComponentValue::Dimension(Dimension::Length(Length {
+        span: DUMMY_SP,
+        value: Number {
+            span: DUMMY_SP,
+            value: 0.0,
+            raw: None,
+        },
+        unit: Ident {
+            span: DUMMY_SP,
+            value: js_word!("px"),
+            raw: None,
+        },
+    }))
+}
+
+// IE10 doesn't use the standard defaults for the omitted components of
+// `-ms-flex`, so always write all three of them and use `0px` instead of a
+// unitless zero basis
+fn to_ms_flex_value(value: &[ComponentValue]) -> Vec<ComponentValue> {
+    let is_number = |value: &ComponentValue| {
+        matches!(
+            value,
+            ComponentValue::Integer(_) | ComponentValue::Number(_)
+        )
+    };
+    let is_basis = |value: &ComponentValue| {
+        matches!(
+            value,
+            ComponentValue::Dimension(_) | ComponentValue::Percentage(_)
+        )
+    };
+    let to_basis = |value: &ComponentValue| match value {
+        ComponentValue::Integer(Integer { value: 0, .. }) => to_zero_px(),
+        _ => value.clone(),
+    };
+
+    match value {
+        [ComponentValue::Ident(Ident { value, .. })] if value.eq_ignore_ascii_case("none") => {
+            vec![to_integer!(0), to_integer!(0), to_ident!("auto")]
+        }
+        [ComponentValue::Ident(Ident { value, .. })] if value.eq_ignore_ascii_case("auto") => {
+            vec![to_integer!(1), to_integer!(1), to_ident!("auto")]
+        }
+        [grow] if is_number(grow) => vec![grow.clone(), to_integer!(1), to_zero_px()],
+        [basis] if is_basis(basis) => vec![to_integer!(1), to_integer!(1), basis.clone()],
+        [grow, shrink] if is_number(grow) && is_number(shrink) => {
+            vec![grow.clone(), shrink.clone(), to_zero_px()]
+        }
+        [grow, basis] if is_number(grow) && is_basis(basis) => {
+            vec![grow.clone(), to_integer!(1), basis.clone()]
+        }
+        [grow, shrink, basis] => vec![grow.clone(), shrink.clone(), to_basis(basis)],
+        _ => value.to_vec(),
+    }
+}
+
+fn is_solidus(value: &ComponentValue) -> bool {
+    matches!(
+        value,
+        ComponentValue::Delimiter(Delimiter {
+            value: DelimiterValue::Solidus,
+            ..
+        })
+    )
+}
+
+fn to_ms_grid_line(value: &[ComponentValue]) -> Option<i64> {
+    match value {
+        [ComponentValue::Integer(Integer { value, .. })] if *value > 0 => Some(*value),
+        _ => None,
+    }
+}
+
+fn to_ms_grid_span(value: &[ComponentValue]) -> Option<i64> {
+    match value {
+        [ComponentValue::Ident(Ident { value, .. }), rest @ ..]
+            if value.eq_ignore_ascii_case("span") =>
+        {
+            to_ms_grid_line(rest)
+        }
+        _ => None,
+    }
+}
+
+// The old grid spec only supports positive line numbers, the spanned tracks are
+// set with a separate property, returns the start line and the number of
+// spanned tracks
+fn to_ms_grid_placement(
+    start: &[ComponentValue],
+    end: Option<&[ComponentValue]>,
+) -> (Option<i64>, Option<i64>) {
+    let end = match end {
+        Some(end) => end,
+        None => return (to_ms_grid_line(start), to_ms_grid_span(start)),
+    };
+
+    let start = to_ms_grid_line(start);
+    let span = to_ms_grid_span(end)
+        .or_else(|| Some(to_ms_grid_line(end)? - start?))
+        .filter(|span| *span > 0);
+
+    if start.is_some() && span.is_some() {
+        (start, span)
+    } else {
+        (None, None)
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum Prefix {
     Webkit,
@@ -557,6 +768,7 @@ struct Prefixer {
     added_at_rules: Vec<(Prefix, Box<AtRule>)>,
     added_qualified_rules: Vec<(Prefix, Box<QualifiedRule>)>,
     added_declarations: Vec<Box<Declaration>>,
+    expand_place_shorthands: bool,
 }
 
 impl Prefixer {
@@ -697,6 +909,35 @@ impl VisitMut for Prefixer {
                     );
                 }
             }
+            AtRuleName::Ident(Ident { value, .. })
+                if value.as_ref().eq_ignore_ascii_case("media") =>
+            {
+                // IE and legacy Edge don't support `forced-colors`, but have the
+                // `-ms-high-contrast` media feature with the same `active` and `none` values
+                if should_prefix("-ms-high-contrast", self.env, false) {
+                    if let Some(prelude) = &at_rule.prelude {
+                        let mut new_prelude = prelude.clone();
+
+                        replace_media_feature_name_on_legacy_variant(
+                            &mut *new_prelude,
+                            "forced-colors",
+                            "-ms-high-contrast",
+                        );
+
+                        if !new_prelude.eq_ignore_span(prelude) {
+                            self.add_at_rule(
+                                Prefix::Ms,
+                                &AtRule {
+                                    span: at_rule.span,
+                                    name: at_rule.name.clone(),
+                                    prelude: Some(new_prelude),
+                                    block: original_simple_block,
+                                },
+                            );
+                        }
+                    }
+                }
+            }
             _ => {}
         }
     }
@@ -1550,6 +1791,10 @@ impl VisitMut for Prefixer {
                 }
             }
 
+            "initial-letter" => {
+                add_declaration!(Prefix::Webkit, "-webkit-initial-letter", None);
+            }
+
             "box-decoration-break" => {
                 add_declaration!(Prefix::Webkit, "-webkit-box-decoration-break", None);
             }
@@ -1578,6 +1823,8 @@ impl VisitMut for Prefixer {
                 add_declaration!(Prefix::Moz, "-moz-column-width", None);
             }
 
+            // The `gap` shorthand is not prefixed, because `-moz-column-gap` and
+            // `-webkit-column-gap` are only for multi-column layout
             "column-gap" => {
                 add_declaration!(Prefix::Webkit, "-webkit-column-gap", None);
                 add_declaration!(Prefix::Moz, "-moz-column-gap", None);
@@ -1708,6 +1955,14 @@ impl VisitMut for Prefixer {
                     if should_prefix("-ms-inline-flexbox", self.env, false) {
                         replace_ident(&mut ms_value, "inline-flex", "-ms-inline-flexbox");
                     }
+
+                    if should_prefix("-ms-grid", self.env, false) {
+                        replace_ident(&mut ms_value, "grid", "-ms-grid");
+                    }
+
+                    if should_prefix("-ms-inline-grid", self.env, false) {
+                        replace_ident(&mut ms_value, "inline-grid", "-ms-inline-grid");
+                    }
                 }
             }
 
@@ -1757,38 +2012,11 @@ impl VisitMut for Prefixer {
                     add_declaration!(Prefix::Webkit, "-moz-box-flex", None);
                 }
 
-                if n.value.len() == 3 {
-                    add_declaration!(
-                        Prefix::Ms,
-                        "-ms-flex",
-                        Some(Box::new(|| {
-                            let mut value = ms_value.clone();
-
-                            if let Some(ComponentValue::Integer(Integer {
-                                value: 0, span, ..
-                            })) = value.get(2)
-                            {
-                                value[2] = ComponentValue::Dimension(Dimension::Length(Length {
-                                    span: *span,
-                                    value: Number {
-                                        span: DUMMY_SP,
-                                        value: 0.0,
-                                        raw: None,
-                                    },
-                                    unit: Ident {
-                                        span: DUMMY_SP,
-                                        value: js_word!("px"),
-                                        raw: None,
-                                    },
-                                }));
-                            }
-
-                            value
-                        }))
-                    );
-                } else {
-                    add_declaration!(Prefix::Ms, "-ms-flex", None);
-                }
+                add_declaration!(
+                    Prefix::Ms,
+                    "-ms-flex",
+                    Some(Box::new(|| { to_ms_flex_value(&ms_value) }))
+                );
             }
 
             "flex-grow" => {
@@ -1866,7 +2094,18 @@ impl VisitMut for Prefixer {
 
             "flex-wrap" => {
                 add_declaration!(Prefix::Webkit, "-webkit-flex-wrap", None);
-                add_declaration!(Prefix::Ms, "-ms-flex-wrap", None);
+                add_declaration!(
+                    Prefix::Ms,
+                    "-ms-flex-wrap",
+                    Some(Box::new(|| {
+                        let mut spec_2012_ms_value = ms_value.clone();
+
+                        // IE10 uses `none` instead of `nowrap`
+                        replace_ident(&mut spec_2012_ms_value, "nowrap", "none");
+
+                        spec_2012_ms_value
+                    }))
+                );
             }
 
             "flex-flow" => {
@@ -1935,7 +2174,20 @@ impl VisitMut for Prefixer {
                     );
                 }
 
-                add_declaration!(Prefix::Ms, "-ms-flex-flow", None);
+                // IE10 supports the `-ms-flex-flow` shorthand, so it is not split into
+                // longhands, but `nowrap` is `none` there, the same as for
+                // `-ms-flex-wrap`
+                add_declaration!(
+                    Prefix::Ms,
+                    "-ms-flex-flow",
+                    Some(Box::new(|| {
+                        let mut spec_2012_ms_value = ms_value.clone();
+
+                        replace_ident(&mut spec_2012_ms_value, "nowrap", "none");
+
+                        spec_2012_ms_value
+                    }))
+                );
             }
 
             "justify-content" => {
@@ -2106,6 +2358,88 @@ impl VisitMut for Prefixer {
                 );
             }
 
+            "place-content" | "place-items" | "place-self"
+                if self.expand_place_shorthands
+                    && (1..=2).contains(&ms_value.len())
+                    && ms_value
+                        .iter()
+                        .all(|n| matches!(n, ComponentValue::Ident(_))) =>
+            {
+                // The first value is the `align-*` part, the second one is the `justify-*`
+                // part, if it is omitted the first one is used for both
+                let align_value = vec![ms_value[0].clone()];
+                let justify_value = vec![ms_value[ms_value.len() - 1].clone()];
+
+                match property_name {
+                    "place-content" => {
+                        add_declaration!(
+                            Prefix::Ms,
+                            "-ms-flex-line-pack",
+                            Some(Box::new(|| {
+                                let mut spec_2012_ms_value = align_value.clone();
+
+                                replace_ident(&mut spec_2012_ms_value, "flex-end", "end");
+                                replace_ident(&mut spec_2012_ms_value, "flex-start", "start");
+                                replace_ident(&mut spec_2012_ms_value, "space-between", "justify");
+                                replace_ident(
+                                    &mut spec_2012_ms_value,
+                                    "space-around",
+                                    "distribute",
+                                );
+
+                                spec_2012_ms_value
+                            }))
+                        );
+                        add_declaration!(
+                            Prefix::Ms,
+                            "-ms-flex-pack",
+                            Some(Box::new(|| {
+                                let mut spec_2012_ms_value = justify_value.clone();
+
+                                replace_ident(&mut spec_2012_ms_value, "flex-start", "start");
+                                replace_ident(&mut spec_2012_ms_value, "flex-end", "end");
+                                replace_ident(&mut spec_2012_ms_value, "space-between", "justify");
+                                replace_ident(
+                                    &mut spec_2012_ms_value,
+                                    "space-around",
+                                    "distribute",
+                                );
+
+                                spec_2012_ms_value
+                            }))
+                        );
+                    }
+                    "place-items" => {
+                        add_declaration!(
+                            Prefix::Ms,
+                            "-ms-flex-align",
+                            Some(Box::new(|| {
+                                let mut spec_2012_ms_value = align_value.clone();
+
+                                replace_ident(&mut spec_2012_ms_value, "flex-end", "end");
+                                replace_ident(&mut spec_2012_ms_value, "flex-start", "start");
+
+                                spec_2012_ms_value
+                            }))
+                        );
+                    }
+                    _ => {
+                        add_declaration!(
+                            Prefix::Ms,
+                            "-ms-flex-item-align",
+                            Some(Box::new(|| {
+                                let mut spec_2012_ms_value = align_value.clone();
+
+                                replace_ident(&mut spec_2012_ms_value, "flex-end", "end");
+                                replace_ident(&mut spec_2012_ms_value, "flex-start", "start");
+
+                                spec_2012_ms_value
+                            }))
+                        );
+                    }
+                }
+            }
+
             "image-rendering" => {
                 if self.rule_prefix == Some(Prefix::Webkit) || self.rule_prefix.is_none() {
                     if should_prefix("-webkit-optimize-contrast:fallback", self.env, false) {
@@ -2153,6 +2487,8 @@ impl VisitMut for Prefixer {
                 }
             }
 
+            // SVG filter references (`url(#id)`) are supported by `-webkit-filter` too and
+            // are copied as is
             "filter" => match &n.value[0] {
                 ComponentValue::PreservedToken(_) => {}
                 ComponentValue::Function(Function { name, .. })
@@ -2283,7 +2619,15 @@ impl VisitMut for Prefixer {
             }
 
             "clip-path" => {
-                add_declaration!(Prefix::Webkit, "-webkit-clip-path", None);
+                // Old WebKit supports only `<basic-shape>`, `<geometry-box>` and `url()`, so
+                // the prefixed declaration with `path()` will be invalid
+                let has_path_function = n.value.iter().any(|n| {
+                    matches!(n, ComponentValue::Function(Function { name, .. }) if name.value.as_ref().eq_ignore_ascii_case("path"))
+                });
+
+                if !has_path_function {
+                    add_declaration!(Prefix::Webkit, "-webkit-clip-path", None);
+                }
             }
 
             "position" if n.value.len() == 1 => {
@@ -2307,7 +2651,8 @@ impl VisitMut for Prefixer {
                                 Some(Box::new(|| { vec![to_ident!("element")] }))
                             );
                         }
-                        "all" => {}
+                        // IE doesn't support `all` and `text`
+                        "all" | "text" => {}
                         _ => {
                             add_declaration!(Prefix::Ms, "-ms-user-select", None);
                         }
@@ -2463,6 +2808,14 @@ impl VisitMut for Prefixer {
                     if should_prefix("-webkit-filter", self.env, false) {
                         replace_ident(&mut webkit_value, "filter", "-webkit-filter");
                     }
+
+                    if should_prefix("-webkit-backdrop-filter", self.env, false) {
+                        replace_ident(
+                            &mut webkit_value,
+                            "backdrop-filter",
+                            "-webkit-backdrop-filter",
+                        );
+                    }
                 }
 
                 add_declaration!(Prefix::Webkit, "-webkit-transition", None);
@@ -2550,6 +2903,9 @@ impl VisitMut for Prefixer {
                     _ => Some("ltr"),
                 };
 
+                // `-webkit-writing-mode` always uses the same values as `writing-mode`, legacy
+                // values from SVG 1.1 (`lr-tb`, `tb-rl` and etc) are only needed for
+                // `-ms-writing-mode`
                 if let ComponentValue::Ident(Ident { value, .. }) = &n.value[0] {
                     match &*value.to_lowercase() {
                         "vertical-lr" => {
@@ -2682,6 +3038,31 @@ impl VisitMut for Prefixer {
                     }
                 }
 
+                // IE10 and IE11 implement the old grid spec, where track lists are
+                // `-ms-grid-columns` and `-ms-grid-rows` and `repeat()` is not supported
+                if matches!(
+                    property_name,
+                    "grid-template-rows" | "grid-template-columns"
+                ) {
+                    let ms_property = if property_name == "grid-template-rows" {
+                        "-ms-grid-rows"
+                    } else {
+                        "-ms-grid-columns"
+                    };
+                    let mut ms_grid_value = ms_value.clone();
+                    let mut repeat_expander = RepeatExpander::default();
+
+                    ms_grid_value.visit_mut_with(&mut repeat_expander);
+
+                    if !repeat_expander.has_unexpandable {
+                        add_declaration!(
+                            Prefix::Ms,
+                            ms_property,
+                            Some(Box::new(|| { ms_grid_value.clone() }))
+                        );
+                    }
+                }
+
                 if !is_grid_property
                     && (self.rule_prefix == Some(Prefix::Moz) || self.rule_prefix.is_none())
                 {
@@ -2705,6 +3086,89 @@ impl VisitMut for Prefixer {
                 }
             }
 
+            "grid-row" | "grid-column" => {
+                let (ms_property, ms_span_property) = if property_name == "grid-row" {
+                    ("-ms-grid-row", "-ms-grid-row-span")
+                } else {
+                    ("-ms-grid-column", "-ms-grid-column-span")
+                };
+
+                let mut parts = n.value.split(is_solidus);
+
+                let (start, span) = match (parts.next(), parts.next(), parts.next()) {
+                    (Some(start), end, None) => to_ms_grid_placement(start, end),
+                    _ => (None, None),
+                };
+
+                if let Some(start) = start {
+                    add_declaration!(
+                        Prefix::Ms,
+                        ms_property,
+                        Some(Box::new(|| { vec![to_integer!(start)] }))
+                    );
+                }
+
+                if let Some(span) = span {
+                    add_declaration!(
+                        Prefix::Ms,
+                        ms_span_property,
+                        Some(Box::new(|| { vec![to_integer!(span)] }))
+                    );
+                }
+            }
+
+            "grid-area" => {
+                let parts: Vec<_> = n.value.split(is_solidus).collect();
+
+                let ((row_start, row_span), (column_start, column_span)) = match &*parts {
+                    [row_start, column_start] => (
+                        to_ms_grid_placement(row_start, None),
+                        to_ms_grid_placement(column_start, None),
+                    ),
+                    [row_start, column_start, row_end] => (
+                        to_ms_grid_placement(row_start, Some(*row_end)),
+                        to_ms_grid_placement(column_start, None),
+                    ),
+                    [row_start, column_start, row_end, column_end] => (
+                        to_ms_grid_placement(row_start, Some(*row_end)),
+                        to_ms_grid_placement(column_start, Some(*column_end)),
+                    ),
+                    _ => ((None, None), (None, None)),
+                };
+
+                if let Some(row_start) = row_start {
+                    add_declaration!(
+                        Prefix::Ms,
+                        "-ms-grid-row",
+                        Some(Box::new(|| { vec![to_integer!(row_start)] }))
+                    );
+                }
+
+                if let Some(column_start) = column_start {
+                    add_declaration!(
+                        Prefix::Ms,
+                        "-ms-grid-column",
+                        Some(Box::new(|| { vec![to_integer!(column_start)] }))
+                    );
+                }
+
+                if let Some(row_span) = row_span {
+                    add_declaration!(
+                        Prefix::Ms,
+                        "-ms-grid-row-span",
+                        Some(Box::new(|| { vec![to_integer!(row_span)] }))
+                    );
+                }
+
+                if let Some(column_span) = column_span {
+                    add_declaration!(
+                        Prefix::Ms,
+                        "-ms-grid-column-span",
+                        Some(Box::new(|| { vec![to_integer!(column_span)] }))
+                    );
+                }
+            }
+
             "touch-action" => {
                 add_declaration!(
                     Prefix::Ms,
@@ -2863,6 +3327,8 @@ impl VisitMut for Prefixer {
                 add_declaration!(Prefix::Webkit, "-webkit-shape-image-threshold", None);
             }
 
+            // IE and legacy Edge don't support `object-fit` and `object-position` at all, so
+            // only old Opera needs a prefix
             "object-fit" => {
                 add_declaration!(Prefix::O, "-o-object-fit", None);
             }
@@ -2872,16 +3338,75 @@ impl VisitMut for Prefixer {
             }
 
             "tab-size" => {
+                // Old Firefox versions accept only `<integer>`, but lengths are passed as is
+                // because they are still valid for the unprefixed property
                 add_declaration!(Prefix::Moz, "-moz-tab-size", None);
                 add_declaration!(Prefix::O, "-o-tab-size", None);
             }
 
             "hyphens" => {
+                // `-ms-hyphens` in IE10+ supports `none`, `manual` and `auto`, so the value can
+                // be copied as is
                 add_declaration!(Prefix::Webkit, "-webkit-hyphens", None);
                 add_declaration!(Prefix::Moz, "-moz-hyphens", None);
                 add_declaration!(Prefix::Ms, "-ms-hyphens", None);
             }
 
+            "line-clamp" | "-webkit-line-clamp" => {
+                let is_standard = property_name == "line-clamp";
+                let need_prefix =
+                    !is_standard || should_prefix("-webkit-line-clamp", self.env, false);
+
+                // `-webkit-line-clamp` only works for the legacy flexbox with the vertical
+                // orientation and the hidden overflow, so add them if they are missing
+                if need_prefix
+                    && matches!(&*n.value, [ComponentValue::Integer(_)])
+                    && (self.rule_prefix == Some(Prefix::Webkit) || self.rule_prefix.is_none())
+                {
+                    let has_hidden_overflow = declarations.iter().any(|declaration| {
+                        let is_overflow = matches!(
+                            &declaration.name,
+                            DeclarationName::Ident(Ident { value, .. })
+                                if value.as_ref().eq_ignore_ascii_case("overflow")
+                        );
+
+                        is_overflow
+                            && matches!(
+                                declaration.value.get(0),
+                                Some(ComponentValue::Ident(Ident { value, .. }))
+                                    if value.as_ref().eq_ignore_ascii_case("hidden")
+                            )
+                    });
+
+                    if is_standard || has_hidden_overflow {
+                        for (property, value) in [
+                            ("display", "-webkit-box"),
+                            ("-webkit-box-orient", "vertical"),
+                            ("overflow", "hidden"),
+                        ] {
+                            if properties.contains(&property) {
+                                continue;
+                            }
+
+                            self.added_declarations.push(Box::new(Declaration {
+                                span: n.span,
+                                name: DeclarationName::Ident(Ident {
+                                    span: DUMMY_SP,
+                                    value: property.into(),
+                                    raw: None,
+                                }),
+                                value: vec![to_ident!(value)],
+                                important: n.important.clone(),
+                            }));
+                        }
+                    }
+                }
+
+                if is_standard {
+                    add_declaration!(Prefix::Webkit, "-webkit-line-clamp", None);
+                }
+            }
+
             "border-image" => {
                 add_declaration!(Prefix::Webkit, "-webkit-border-image", None);
                 add_declaration!(Prefix::Moz, "-moz-border-image", None);
@@ -2892,6 +3417,58 @@ impl VisitMut for Prefixer {
                 add_declaration!(Prefix::Webkit, "-webkit-font-kerning", None);
             }
 
+            "font-variant-numeric" => {
+                // Use `font-feature-settings` as a fallback for browsers without
+                // `font-variant-numeric`
+                let feature_tags = n
+                    .value
+                    .iter()
+                    .map(|n| match n {
+                        ComponentValue::Ident(Ident { value, .. }) => {
+                            match &*value.to_lowercase() {
+                                "lining-nums" => Some("lnum"),
+                                "oldstyle-nums" => Some("onum"),
+                                "proportional-nums" => Some("pnum"),
+                                "tabular-nums" => Some("tnum"),
+                                "diagonal-fractions" => Some("frac"),
+                                "stacked-fractions" => Some("afrc"),
+                                "ordinal" => Some("ordn"),
+                                "slashed-zero" => Some("zero"),
+                                _ => None,
+                            }
+                        }
+                        _ => None,
+                    })
+                    .collect::<Option<Vec<_>>>();
+
+                if let Some(feature_tags) = feature_tags {
+                    add_declaration!(
+                        Prefix::Webkit,
+                        "-webkit-font-feature-settings",
+                        Some(Box::new(|| {
+                            let mut value = Vec::with_capacity(feature_tags.len() * 2);
+
+                            for (i, feature_tag) in feature_tags.iter().enumerate() {
+                                if i > 0 {
+                                    value.push(ComponentValue::Delimiter(Delimiter {
+                                        span: DUMMY_SP,
+                                        value: DelimiterValue::Comma,
+                                    }));
+                                }
+
+                                value.push(ComponentValue::Str(Str {
+                                    span: DUMMY_SP,
+                                    value: (*feature_tag).into(),
+                                    raw: None,
+                                }));
+                            }
+
+                            value
+                        }))
+                    );
+                }
+            }
+
             "font-feature-settings" => {
                 add_declaration!(Prefix::Webkit, "-webkit-font-feature-settings", None);
                 add_declaration!(Prefix::Moz, "-moz-font-feature-settings", None);
@@ -2919,7 +3496,11 @@ impl VisitMut for Prefixer {
                 add_declaration!(Prefix::O, "-o-background-size", None);
             }
 
-            "overscroll-behavior" => {
+            // There is no `-ms-` equivalent for `overscroll-behavior-y`, so it is left as is
+            "overscroll-behavior" | "overscroll-behavior-x" => {
+                // IE11 has no `-ms-overscroll-behavior`, use `-ms-scroll-chaining` instead,
+                // `chained` is the default value and matches `auto`, `none` disables scroll
+                // chaining the same way as `none` and `contain`
                 if let ComponentValue::Ident(Ident { value, .. }) = &n.value[0] {
                     match &*value.to_lowercase() {
                         "auto" => {
@@ -2945,6 +3526,20 @@ impl VisitMut for Prefixer {
                 }
             }
 
+            // `-webkit-overflow-scrolling: touch` enables momentum scrolling rather than
+            // smooth programmatic scrolling, but it is the closest thing old iOS Safari has
+            "scroll-behavior" => {
+                if let ComponentValue::Ident(Ident { value, .. }) = &n.value[0] {
+                    if &*value.to_lowercase() == "smooth" {
+                        add_declaration!(
+                            Prefix::Webkit,
+                            "-webkit-overflow-scrolling",
+                            Some(Box::new(|| { vec![to_ident!("touch")] }))
+                        );
+                    }
+                }
+            }
+
             "box-shadow" => {
                 add_declaration!(Prefix::Webkit, "-webkit-box-shadow", None);
                 add_declaration!(Prefix::Moz, "-moz-box-shadow", None);
@@ -3002,7 +3597,33 @@ impl VisitMut for Prefixer {
             }
 
             "border-radius" => {
-                add_declaration!(Prefix::Webkit, "-webkit-border-radius", None);
+                // Old WebKit doesn't support the slash syntax, but uses two values as
+                // horizontal and vertical radii for all corners
+                let is_single_elliptical_radius = n.value.len() == 3
+                    && matches!(
+                        n.value.get(1),
+                        Some(ComponentValue::Delimiter(Delimiter {
+                            value: DelimiterValue::Solidus,
+                            ..
+                        }))
+                    );
+
+                if is_single_elliptical_radius {
+                    add_declaration!(
+                        Prefix::Webkit,
+                        "-webkit-border-radius",
+                        Some(Box::new(|| {
+                            let mut value = webkit_value.clone();
+
+                            value.remove(1);
+
+                            value
+                        }))
+                    );
+                } else {
+                    add_declaration!(Prefix::Webkit, "-webkit-border-radius", None);
+                }
+
                 add_declaration!(Prefix::Moz, "-moz-border-radius", None);
             }
 
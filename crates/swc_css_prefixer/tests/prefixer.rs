@@ -73,7 +73,20 @@ fn test_with_env(input: PathBuf) {
             env: Some(Targets::Query(Query::Single(String::from(
                 "defaults, not IE 11",
             )))),
+            ..Default::default()
         },
         Some("defaults-not-ie-11"),
     )
 }
+
+#[testing::fixture("tests/options/expand_place_shorthands/**/input.css")]
+fn test_expand_place_shorthands(input: PathBuf) {
+    prefix(
+        input,
+        Options {
+            expand_place_shorthands: true,
+            ..Default::default()
+        },
+        None,
+    )
+}
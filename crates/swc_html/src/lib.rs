@@ -2,3 +2,5 @@ pub extern crate swc_html_ast as ast;
 pub extern crate swc_html_codegen as codegen;
 pub extern crate swc_html_parser as parser;
 pub extern crate swc_html_visit as visit;
+#[cfg(feature = "transforms")]
+pub extern crate swc_html_transforms as transforms;
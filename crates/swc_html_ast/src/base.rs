@@ -1,7 +1,7 @@
 use is_macro::Is;
 use string_enum::StringEnum;
 use swc_atoms::{Atom, JsWord};
-use swc_common::{ast_node, EqIgnoreSpan, Span};
+use swc_common::{ast_node, EqIgnoreSpan, Span, DUMMY_SP};
 
 #[ast_node("Document")]
 #[derive(Eq, Hash, EqIgnoreSpan)]
@@ -11,6 +11,40 @@ pub struct Document {
     pub children: Vec<Child>,
 }
 
+impl Document {
+    /// Returns the `href` of the first `<base>` element with an `href`
+    /// attribute in `<head>`.
+    ///
+    /// The value is returned as written, an empty `href` or `href` without a
+    /// value gives `Some("")` and later `<base>` elements are ignored.
+    pub fn base_uri(&self) -> Option<&str> {
+        self.children
+            .iter()
+            .find_map(|child| match child {
+                Child::Element(element) if is_html_element(element, "html") => Some(element),
+                _ => None,
+            })
+            .and_then(|html| {
+                html.children.iter().find_map(|child| match child {
+                    Child::Element(element) if is_html_element(element, "head") => Some(element),
+                    _ => None,
+                })
+            })
+            .and_then(|head| {
+                head.children.iter().find_map(|child| match child {
+                    Child::Element(element) if is_html_element(element, "base") => {
+                        element.get_attribute("href")
+                    }
+                    _ => None,
+                })
+            })
+    }
+}
+
+fn is_html_element(element: &Element, tag_name: &str) -> bool {
+    element.namespace == Namespace::HTML && &*element.tag_name == tag_name
+}
+
 #[ast_node("DocumentFragment")]
 #[derive(Eq, Hash, EqIgnoreSpan)]
 pub struct DocumentFragment {
@@ -53,6 +87,60 @@ pub enum Child {
     Comment(Comment),
 }
 
+impl Child {
+    /// Returns the first node matching `predicate` in the depth-first order,
+    /// starting with `self`.
+    ///
+    /// The content of `<template>` elements is not searched.
+    pub fn find_first<F>(&self, predicate: F) -> Option<&Child>
+    where
+        F: Fn(&Child) -> bool,
+    {
+        find_first(self, &predicate)
+    }
+
+    /// Returns all nodes matching `predicate` in the depth-first order,
+    /// starting with `self`.
+    ///
+    /// The content of `<template>` elements is not searched.
+    pub fn find_all<F>(&self, predicate: F) -> Vec<&Child>
+    where
+        F: Fn(&Child) -> bool,
+    {
+        let mut found = vec![];
+
+        find_all(self, &predicate, &mut found);
+
+        found
+    }
+}
+
+fn find_first<'a>(node: &'a Child, predicate: &dyn Fn(&Child) -> bool) -> Option<&'a Child> {
+    if predicate(node) {
+        return Some(node);
+    }
+
+    match node {
+        Child::Element(element) => element
+            .children
+            .iter()
+            .find_map(|child| find_first(child, predicate)),
+        _ => None,
+    }
+}
+
+fn find_all<'a>(node: &'a Child, predicate: &dyn Fn(&Child) -> bool, found: &mut Vec<&'a Child>) {
+    if predicate(node) {
+        found.push(node);
+    }
+
+    if let Child::Element(element) = node {
+        for child in element.children.iter() {
+            find_all(child, predicate, found);
+        }
+    }
+}
+
 #[ast_node("DocumentType")]
 #[derive(Eq, Hash)]
 pub struct DocumentType {
@@ -116,6 +204,62 @@ pub struct Element {
     pub is_self_closing: bool,
 }
 
+impl Element {
+    /// Returns the value of the attribute named `name`, compared ASCII
+    /// case-insensitively.
+    ///
+    /// Like `getAttribute` in DOM, an attribute without a value, i.e.
+    /// `<input disabled>`, gives `Some("")` and a missing attribute gives
+    /// `None`.
+    ///
+    /// Attributes with a namespace, i.e. `xlink:href`, are not matched.
+    pub fn get_attribute(&self, name: &str) -> Option<&str> {
+        self.get_attribute_node(name)
+            .map(|attribute| attribute.value.as_deref().unwrap_or_default())
+    }
+
+    /// Returns the attribute named `name` like [Element::get_attribute], i.e.
+    /// to get its span for diagnostics.
+    pub fn get_attribute_node(&self, name: &str) -> Option<&Attribute> {
+        self.attributes.iter().find(|attribute| {
+            attribute.namespace.is_none() && attribute.name.eq_ignore_ascii_case(name)
+        })
+    }
+
+    /// Returns `true` if the element has the attribute named `name`, compared
+    /// ASCII case-insensitively.
+    pub fn has_attribute(&self, name: &str) -> bool {
+        self.get_attribute_node(name).is_some()
+    }
+
+    /// Sets the value of the attribute named `name`, adding it if missing.
+    ///
+    /// `None` produces an attribute without a value, i.e. `<input disabled>`.
+    pub fn set_attribute(&mut self, name: &str, value: Option<&str>) {
+        let value = value.map(JsWord::from);
+
+        match self.attributes.iter_mut().find(|attribute| {
+            attribute.namespace.is_none() && attribute.name.eq_ignore_ascii_case(name)
+        }) {
+            Some(attribute) => {
+                attribute.value = value;
+                attribute.raw_value = None;
+            }
+            None => {
+                self.attributes.push(Attribute {
+                    span: DUMMY_SP,
+                    namespace: None,
+                    prefix: None,
+                    name: name.to_ascii_lowercase().into(),
+                    raw_name: None,
+                    value,
+                    raw_value: None,
+                });
+            }
+        }
+    }
+}
+
 #[ast_node("Attribute")]
 #[derive(Eq, Hash)]
 pub struct Attribute {
@@ -131,6 +275,49 @@ pub struct Attribute {
     pub raw_value: Option<Atom>,
 }
 
+// https://html.spec.whatwg.org/multipage/indices.html#attributes-3
+//
+// Must be sorted, because it is searched with `binary_search`
+static BOOLEAN_ATTRIBUTES: &[&str] = &[
+    "allowfullscreen",
+    "async",
+    "autofocus",
+    "autoplay",
+    "checked",
+    "controls",
+    "default",
+    "defer",
+    "disabled",
+    "formnovalidate",
+    "hidden",
+    "inert",
+    "ismap",
+    "itemscope",
+    "loop",
+    "multiple",
+    "muted",
+    "nomodule",
+    "novalidate",
+    "open",
+    "playsinline",
+    "readonly",
+    "required",
+    "reversed",
+    "selected",
+];
+
+impl Attribute {
+    /// Returns `true` for boolean attributes, i.e. `disabled`, `checked` and
+    /// etc, the presence of such attribute represents the true value.
+    pub fn is_boolean(&self) -> bool {
+        self.namespace.is_none()
+            && self.prefix.is_none()
+            && BOOLEAN_ATTRIBUTES
+                .binary_search(&&*self.name.to_ascii_lowercase())
+                .is_ok()
+    }
+}
+
 impl EqIgnoreSpan for Attribute {
     fn eq_ignore_span(&self, other: &Self) -> bool {
         self.namespace == other.namespace
@@ -169,3 +356,116 @@ impl EqIgnoreSpan for Comment {
         self.data == other.data
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn element(attributes: &[(&str, Option<&str>)]) -> Element {
+        Element {
+            span: DUMMY_SP,
+            tag_name: "input".into(),
+            namespace: Namespace::HTML,
+            attributes: attributes
+                .iter()
+                .map(|(name, value)| Attribute {
+                    span: DUMMY_SP,
+                    namespace: None,
+                    prefix: None,
+                    name: (*name).into(),
+                    raw_name: None,
+                    value: value.map(Into::into),
+                    raw_value: None,
+                })
+                .collect(),
+            children: vec![],
+            content: None,
+            is_self_closing: false,
+        }
+    }
+
+    fn attribute(name: &str) -> Attribute {
+        element(&[(name, None)]).attributes.remove(0)
+    }
+
+    #[test]
+    fn boolean_attributes_are_sorted() {
+        assert!(BOOLEAN_ATTRIBUTES.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn attribute_is_boolean() {
+        assert!(attribute("allowfullscreen").is_boolean());
+        assert!(attribute("disabled").is_boolean());
+        assert!(attribute("selected").is_boolean());
+        assert!(attribute("DISABLED").is_boolean());
+        assert!(attribute("noModule").is_boolean());
+
+        assert!(!attribute("class").is_boolean());
+        assert!(!attribute("disable").is_boolean());
+        assert!(!attribute("").is_boolean());
+    }
+
+    #[test]
+    fn namespaced_attribute_is_not_boolean() {
+        let mut attribute = attribute("hidden");
+
+        attribute.prefix = Some("xlink".into());
+
+        assert!(!attribute.is_boolean());
+    }
+
+    #[test]
+    fn element_get_attribute() {
+        let element = element(&[("type", Some("text")), ("disabled", None)]);
+
+        assert_eq!(element.get_attribute("type"), Some("text"));
+        assert_eq!(element.get_attribute("TYPE"), Some("text"));
+        assert_eq!(element.get_attribute("disabled"), Some(""));
+        assert_eq!(element.get_attribute("value"), None);
+    }
+
+    #[test]
+    fn element_get_attribute_node() {
+        let element = element(&[("type", Some("text")), ("disabled", None)]);
+
+        assert_eq!(
+            element
+                .get_attribute_node("TYPE")
+                .map(|attribute| &*attribute.name),
+            Some("type")
+        );
+        assert_eq!(
+            element
+                .get_attribute_node("disabled")
+                .map(|attribute| attribute.value.is_none()),
+            Some(true)
+        );
+        assert!(element.get_attribute_node("value").is_none());
+    }
+
+    #[test]
+    fn element_has_attribute() {
+        let element = element(&[("type", Some("text")), ("disabled", None)]);
+
+        assert!(element.has_attribute("type"));
+        assert!(element.has_attribute("Disabled"));
+        assert!(!element.has_attribute("value"));
+    }
+
+    #[test]
+    fn element_set_attribute() {
+        let mut element = element(&[("type", Some("text"))]);
+
+        element.set_attribute("TYPE", Some("password"));
+        element.set_attribute("Required", None);
+        element.set_attribute("value", Some("a"));
+
+        assert_eq!(element.attributes.len(), 3);
+        assert_eq!(element.get_attribute("type"), Some("password"));
+        assert_eq!(&*element.attributes[0].name, "type");
+        assert_eq!(element.attributes[1].value, None);
+        assert_eq!(&*element.attributes[1].name, "required");
+        assert_eq!(element.get_attribute("value"), Some("a"));
+    }
+}
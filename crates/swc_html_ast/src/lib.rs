@@ -2,6 +2,10 @@
 #![allow(clippy::large_enum_variant)]
 
 //! AST definitions for HTML.
+//!
+//! All nodes implement `serde::Serialize` and `serde::Deserialize`, so a
+//! document can be dumped to JSON with `serde_json`. Nodes are tagged with
+//! `"type"` and spans are serialized as `{ "start", "end", "ctxt" }`.
 pub use self::{base::*, token::*};
 
 mod base;
@@ -3,14 +3,18 @@
 #![allow(clippy::match_like_matches_macro)]
 
 pub use std::fmt::Result;
-use std::{iter::Peekable, str::Chars};
+use std::{borrow::Cow, fmt::Write, io, iter::Peekable, str::Chars};
 
 use swc_atoms::{js_word, JsWord};
 use swc_common::Spanned;
 use swc_html_ast::*;
 use swc_html_codegen_macros::emitter;
 use swc_html_utils::HTML_ENTITIES;
-use writer::HtmlWriter;
+use writer::{
+    basic::{BasicHtmlWriter, BasicHtmlWriterConfig},
+    io::IoWriter,
+    HtmlWriter,
+};
 
 pub use self::emit::*;
 use self::{ctx::Ctx, list::ListFormat};
@@ -28,7 +32,8 @@ pub struct CodegenConfig<'a> {
     pub scripting_enabled: bool,
     /// Should be used only for `DocumentFragment` code generation
     pub context_element: Option<&'a Element>,
-    /// Don't print optional tags (only when `minify` enabled)
+    /// Don't print optional start and end tags, following the tag omission
+    /// rules from the HTML spec
     /// By default `true` when `minify` enabled, otherwise `false`
     pub tag_omission: Option<bool>,
     /// Making SVG and MathML elements self-closing where possible (only when
@@ -38,6 +43,141 @@ pub struct CodegenConfig<'a> {
     /// Always print quotes or remove them where possible (only when `minify`
     /// enabled) By default `false` when `minify` enabled, otherwise `true`
     pub quotes: Option<bool>,
+    /// How to print void elements in HTML namespace, i.e. `<br>` or `<br />`
+    pub void_element_style: VoidElementStyle,
+    /// Order of attributes in the output
+    pub emit_attribute_order: AttributeOrder,
+    /// How to wrap the content of `<script>` elements for XHTML consumers
+    pub cdata_script_style: CdataStyle,
+    /// How to print non-ASCII characters in text and attribute values
+    pub char_encoding: CharEncoding,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoidElementStyle {
+    /// `<br>`
+    Html5,
+    /// `<br />`
+    Xhtml,
+}
+
+impl Default for VoidElementStyle {
+    fn default() -> Self {
+        VoidElementStyle::Html5
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeOrder {
+    /// Keep the order from the AST
+    Preserve,
+    /// Sort by name
+    Alphabetical,
+    /// `class` and `id` first, then sort by name
+    Canonical,
+}
+
+impl Default for AttributeOrder {
+    fn default() -> Self {
+        AttributeOrder::Preserve
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CdataStyle {
+    /// Print the content as is
+    None,
+    /// `// <![CDATA[` ... `// ]]>`, only for JavaScript, data blocks like
+    /// `application/ld+json` are printed as is
+    Comment,
+    /// `<![CDATA[` ... `]]>`, only for `<script>` in SVG and MathML, because
+    /// CDATA sections are not allowed in HTML
+    Cdata,
+}
+
+impl Default for CdataStyle {
+    fn default() -> Self {
+        CdataStyle::None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharEncoding {
+    /// Print characters as is
+    Utf8,
+    /// Print characters above U+007F as numeric character references, i.e.
+    /// `&#xE9;`, the content of raw text elements (`<script>`, `<style>`
+    /// and etc) and comments is printed as is
+    AsciiSafe,
+}
+
+impl Default for CharEncoding {
+    fn default() -> Self {
+        CharEncoding::Utf8
+    }
+}
+
+/// Emit `node` to a string using [BasicHtmlWriter] with the default config.
+///
+/// ```ignore
+/// let html = emit_to_string(&document, Default::default()).unwrap();
+/// ```
+pub fn emit_to_string<'a, N>(
+    node: &N,
+    config: CodegenConfig<'a>,
+) -> std::result::Result<String, std::fmt::Error>
+where
+    N: Spanned,
+    for<'w> CodeGenerator<'a, BasicHtmlWriter<'w, &'w mut String>>: Emit<N>,
+{
+    let mut buf = String::new();
+
+    {
+        let wr = BasicHtmlWriter::new(&mut buf, None, BasicHtmlWriterConfig::default());
+        let mut gen = CodeGenerator::new(wr, config);
+
+        gen.emit(node)?;
+    }
+
+    Ok(buf)
+}
+
+/// Emit `node` to UTF-8 bytes, see [emit_to_string].
+pub fn emit_to_bytes<'a, N>(
+    node: &N,
+    config: CodegenConfig<'a>,
+) -> std::result::Result<Vec<u8>, std::fmt::Error>
+where
+    N: Spanned,
+    for<'w> CodeGenerator<'a, BasicHtmlWriter<'w, &'w mut String>>: Emit<N>,
+{
+    emit_to_string(node, config).map(String::into_bytes)
+}
+
+/// Emit `node` to `writer`, see [emit_to_string].
+///
+/// The output is written while the code is generated, wrap `writer` in
+/// [io::BufWriter] to avoid many small writes.
+pub fn emit_to_writer<'a, N, W>(node: &N, config: CodegenConfig<'a>, writer: W) -> io::Result<()>
+where
+    N: Spanned,
+    W: io::Write,
+    for<'w> CodeGenerator<'a, BasicHtmlWriter<'w, &'w mut IoWriter<W>>>: Emit<N>,
+{
+    let mut writer = IoWriter::new(writer);
+
+    let result = {
+        let wr = BasicHtmlWriter::new(&mut writer, None, BasicHtmlWriterConfig::default());
+        let mut gen = CodeGenerator::new(wr, config);
+
+        gen.emit(node)
+    };
+
+    result.map_err(|err| {
+        writer
+            .take_error()
+            .unwrap_or_else(|| io::Error::new(io::ErrorKind::Other, err))
+    })
 }
 
 enum TagOmissionParent<'a> {
@@ -340,23 +480,30 @@ where
         };
 
         if !can_omit_start_tag {
+            let attributes = self.sort_attributes(&n.attributes);
+
             write_raw!(self, "<");
             write_raw!(self, &n.tag_name);
 
             if has_attributes {
                 space!(self);
 
-                self.emit_list(&n.attributes, ListFormat::SpaceDelimited)?;
+                self.emit_list(&attributes, ListFormat::SpaceDelimited)?;
             }
 
-            if (matches!(n.namespace, Namespace::SVG | Namespace::MATHML) && is_void_element)
+            if is_void_element
+                && n.namespace == Namespace::HTML
+                && self.config.void_element_style == VoidElementStyle::Xhtml
+            {
+                write_raw!(self, " /");
+            } else if (matches!(n.namespace, Namespace::SVG | Namespace::MATHML) && is_void_element)
                 || (self.self_closing_void_elements
                     && n.is_self_closing
                     && is_void_element
                     && matches!(n.namespace, Namespace::HTML))
             {
                 if self.config.minify {
-                    let need_space = match n.attributes.last() {
+                    let need_space = match attributes.last() {
                         Some(Attribute {
                             value: Some(value), ..
                         }) => !value.chars().any(|c| match c {
@@ -413,6 +560,12 @@ where
                 }
             }
 
+            let cdata_wrapper = self.get_cdata_wrapper(n);
+
+            if let Some((start, _)) = cdata_wrapper {
+                write_raw!(self, start);
+            }
+
             if self.tag_omission {
                 self.with_ctx(ctx)
                     .emit_list_for_tag_omission(TagOmissionParent::Element(n))?;
@@ -420,6 +573,10 @@ where
                 self.with_ctx(ctx)
                     .emit_list(&n.children, ListFormat::NotDelimited)?;
             }
+
+            if let Some((_, end)) = cdata_wrapper {
+                write_raw!(self, end);
+            }
         }
 
         let can_omit_end_tag = self.is_plaintext
@@ -803,15 +960,13 @@ where
         if let Some(value) = &n.value {
             attribute.push('=');
 
-            if self.config.minify {
-                let minifier = minify_attribute_value(value, self.quotes);
-
-                attribute.push_str(&minifier);
+            let value = if self.config.minify {
+                minify_attribute_value(value, self.quotes)
             } else {
-                let normalized = normalize_attribute_value(value);
+                normalize_attribute_value(value)
+            };
 
-                attribute.push_str(&normalized);
-            }
+            self.encode_chars(&value, &mut attribute)?;
         }
 
         write_multiline_raw!(self, n.span, &attribute);
@@ -820,13 +975,14 @@ where
     #[emitter]
     fn emit_text(&mut self, n: &Text) -> Result {
         if self.ctx.need_escape_text {
-            let mut data = String::with_capacity(n.data.len());
-
-            if self.config.minify {
-                data.push_str(&minify_text(&n.data));
+            let escaped = if self.config.minify {
+                minify_text(&n.data)
             } else {
-                data.push_str(&escape_string(&n.data, false));
-            }
+                escape_string(&n.data, false)
+            };
+            let mut data = String::with_capacity(escaped.len());
+
+            self.encode_chars(&escaped, &mut data)?;
 
             write_multiline_raw!(self, n.span, &data);
         } else {
@@ -845,6 +1001,24 @@ where
         write_multiline_raw!(self, n.span, &comment);
     }
 
+    fn encode_chars(&self, value: &str, buf: &mut String) -> Result {
+        if self.config.char_encoding == CharEncoding::Utf8 || value.is_ascii() {
+            buf.push_str(value);
+
+            return Ok(());
+        }
+
+        for c in value.chars() {
+            if c.is_ascii() {
+                buf.push(c);
+            } else {
+                write!(buf, "&#x{:X};", c as u32)?;
+            }
+        }
+
+        Ok(())
+    }
+
     fn create_context_for_element(&self, n: &Element) -> Ctx {
         let need_escape_text = match n.tag_name {
             js_word!("style")
@@ -865,6 +1039,70 @@ where
         }
     }
 
+    fn get_cdata_wrapper(&self, n: &Element) -> Option<(&'static str, &'static str)> {
+        if n.tag_name != js_word!("script") {
+            return None;
+        }
+
+        let wrapper = match self.config.cdata_script_style {
+            CdataStyle::None => return None,
+            // Data blocks, i.e. `application/ld+json`, don't accept `//` comments
+            CdataStyle::Comment if is_javascript_script(n) => ("// <![CDATA[\n", "\n// ]]>"),
+            CdataStyle::Comment => return None,
+            CdataStyle::Cdata if n.namespace != Namespace::HTML => ("<![CDATA[", "]]>"),
+            CdataStyle::Cdata => return None,
+        };
+
+        let mut has_content = false;
+
+        for child in n.children.iter() {
+            match child {
+                // Already wrapped or can't be wrapped
+                Child::Text(Text { data, .. })
+                    if data.contains("<![CDATA[") || data.contains("]]>") =>
+                {
+                    return None;
+                }
+                Child::Text(Text { data, .. }) => {
+                    has_content = has_content || !data.trim().is_empty();
+                }
+                _ => return None,
+            }
+        }
+
+        if has_content {
+            Some(wrapper)
+        } else {
+            None
+        }
+    }
+
+    fn sort_attributes<'b>(&self, attributes: &'b [Attribute]) -> Cow<'b, [Attribute]> {
+        let rank = |attribute: &Attribute| match self.config.emit_attribute_order {
+            AttributeOrder::Canonical if attribute.namespace.is_none() => match &*attribute.name {
+                "class" => 0,
+                "id" => 1,
+                _ => 2,
+            },
+            _ => 2,
+        };
+
+        match self.config.emit_attribute_order {
+            AttributeOrder::Preserve => Cow::Borrowed(attributes),
+            AttributeOrder::Alphabetical | AttributeOrder::Canonical => {
+                let mut attributes = attributes.to_vec();
+
+                attributes.sort_by(|a, b| {
+                    rank(a)
+                        .cmp(&rank(b))
+                        .then_with(|| a.name.as_ref().cmp(b.name.as_ref()))
+                });
+
+                Cow::Owned(attributes)
+            }
+        }
+    }
+
     fn emit_list_for_tag_omission(&mut self, parent: TagOmissionParent) -> Result {
         let nodes = match &parent {
             TagOmissionParent::Document(document) => &document.children,
@@ -1138,6 +1376,42 @@ fn escape_string(value: &str, is_attribute_mode: bool) -> String {
     result
 }
 
+// https://html.spec.whatwg.org/multipage/scripting.html#attr-script-type
+fn is_javascript_script(n: &Element) -> bool {
+    let value = match n
+        .attributes
+        .iter()
+        .find(|attribute| attribute.namespace.is_none() && attribute.name == js_word!("type"))
+    {
+        Some(Attribute {
+            value: Some(value), ..
+        }) => value.trim().to_ascii_lowercase(),
+        _ => return true,
+    };
+    let value = value.split(';').next().unwrap_or_default().trim();
+
+    matches!(
+        value,
+        "" | "module"
+            | "application/javascript"
+            | "application/ecmascript"
+            | "application/x-ecmascript"
+            | "application/x-javascript"
+            | "text/ecmascript"
+            | "text/javascript"
+            | "text/javascript1.0"
+            | "text/javascript1.1"
+            | "text/javascript1.2"
+            | "text/javascript1.3"
+            | "text/javascript1.4"
+            | "text/javascript1.5"
+            | "text/jscript"
+            | "text/livescript"
+            | "text/x-ecmascript"
+            | "text/x-javascript"
+    )
+}
+
 fn is_html_tag_name(namespace: Namespace, tag_name: &JsWord) -> bool {
     if namespace != Namespace::HTML {
         return false;
@@ -0,0 +1,45 @@
+use std::{fmt, io};
+
+/// Adapts [io::Write] to [fmt::Write], so the code generator can write to
+/// files and sockets without building the whole output in memory.
+///
+/// `fmt::Write` can't return the I/O error, so it is stored and can be taken
+/// with [IoWriter::take_error] after a write fails.
+pub struct IoWriter<W>
+where
+    W: io::Write,
+{
+    inner: W,
+    error: Option<io::Error>,
+}
+
+impl<W> IoWriter<W>
+where
+    W: io::Write,
+{
+    pub fn new(inner: W) -> Self {
+        IoWriter { inner, error: None }
+    }
+
+    /// Returns the error of the last failed write.
+    pub fn take_error(&mut self) -> Option<io::Error> {
+        self.error.take()
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W> fmt::Write for IoWriter<W>
+where
+    W: io::Write,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|err| {
+            self.error = Some(err);
+
+            fmt::Error
+        })
+    }
+}
@@ -4,6 +4,7 @@ use auto_impl::auto_impl;
 use swc_common::Span;
 
 pub mod basic;
+pub mod io;
 
 #[auto_impl(&mut, Box)]
 pub trait HtmlWriter {
@@ -0,0 +1,198 @@
+#![deny(warnings)]
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde_json::Value;
+use swc_common::{input::StringInput, BytePos};
+use swc_html_ast::Document;
+use swc_html_codegen::{emit_to_string, CodegenConfig};
+use swc_html_parser::{
+    lexer::Lexer,
+    parser::{Parser, ParserConfig},
+};
+
+/// The minimal percentage of html5lib tree construction documents which should
+/// be printed byte-identical to the input with tag omission.
+///
+/// Keep it slightly below the current rate, raise it when the codegen
+/// preserves more of the source.
+const MIN_FIDELITY_TAG_OMISSION: f64 = 5.0;
+
+/// The same as [MIN_FIDELITY_TAG_OMISSION], but without tag omission, so
+/// implied `<html>`, `<head>` and `<body>` are printed and only documents
+/// which write them explicitly can be identical.
+const MIN_FIDELITY: f64 = 0.5;
+
+/// The same as [MIN_FIDELITY_TAG_OMISSION] for inputs of the html5lib
+/// tokenizer tests.
+const MIN_FIDELITY_TOKENIZER: f64 = 5.0;
+
+/// Parses every document from the html5lib tree construction tests and prints
+/// it back without minification, so the raw values are used, and counts the
+/// documents which are printed exactly as written.
+///
+/// Implied tags are omitted, because they don't exist in the source.
+#[test]
+fn html5lib_round_trip_fidelity_with_tag_omission() {
+    let sources = read_tree_construction_sources();
+
+    assert_fidelity(&sources, Some(true), MIN_FIDELITY_TAG_OMISSION);
+}
+
+/// Same as [html5lib_round_trip_fidelity_with_tag_omission], but all tags are
+/// printed.
+#[test]
+fn html5lib_round_trip_fidelity() {
+    let sources = read_tree_construction_sources();
+
+    assert_fidelity(&sources, Some(false), MIN_FIDELITY);
+}
+
+/// Round-trips the inputs of the html5lib tokenizer tests which start in the
+/// data state.
+///
+/// The tokenizer tests are part of the `html5lib-tests` submodule, the test
+/// fails when it is not checked out.
+#[test]
+fn html5lib_tokenizer_round_trip_fidelity() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../swc_html_parser/tests/html5lib-tests/tokenizer");
+    let mut paths = fs::read_dir(&dir)
+        .map(|entries| {
+            entries
+                .map(|entry| entry.unwrap().path())
+                .filter(|path| path.extension().map_or(false, |ext| ext == "test"))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    assert!(
+        !paths.is_empty(),
+        "html5lib tokenizer tests are missing in {}, run `git submodule update --init \
+         crates/swc_html_parser/tests/html5lib-tests`",
+        dir.display()
+    );
+
+    paths.sort();
+
+    let mut sources = vec![];
+
+    for path in paths.iter() {
+        let obj: Value = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+        let tests = match obj.get("tests") {
+            Some(Value::Array(tests)) => tests,
+            _ => continue,
+        };
+
+        for (index, test) in tests.iter().enumerate() {
+            // Double escaped inputs contain lone surrogates, which can't be represented in
+            // `String`, other states are only reachable inside of specific elements
+            if test.get("doubleEscaped").is_some() || test.get("initialStates").is_some() {
+                continue;
+            }
+
+            if let Some(Value::String(input)) = test.get("input") {
+                sources.push((
+                    format!("{}#{}", path.display(), index),
+                    input.clone(),
+                    false,
+                ));
+            }
+        }
+    }
+
+    assert_fidelity(&sources, Some(true), MIN_FIDELITY_TOKENIZER);
+}
+
+/// Returns the name, the source and whether scripting is enabled for every
+/// document of the html5lib tree construction tests.
+fn read_tree_construction_sources() -> Vec<(String, String, bool)> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../swc_html_parser/tests/html5lib-tests-fixture");
+    let mut paths = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            let file_name = path.file_name().unwrap().to_string_lossy();
+
+            // Fragments need a context element, which is encoded in the file name
+            file_name.ends_with(".html") && !file_name.contains("fragment")
+        })
+        .collect::<Vec<PathBuf>>();
+
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let src = fs::read_to_string(&path).unwrap();
+            let scripting_enabled = path.to_string_lossy().contains("script_on");
+
+            (path.display().to_string(), src, scripting_enabled)
+        })
+        .collect()
+}
+
+fn assert_fidelity(sources: &[(String, String, bool)], tag_omission: Option<bool>, min: f64) {
+    let mut different = vec![];
+
+    for (name, src, scripting_enabled) in sources.iter() {
+        let lexer = Lexer::new(StringInput::new(src, BytePos(0), BytePos(src.len() as u32)));
+        let mut parser = Parser::new(
+            lexer,
+            ParserConfig {
+                scripting_enabled: *scripting_enabled,
+                ..Default::default()
+            },
+        );
+        let document: Document = match parser.parse_document() {
+            Ok(document) => document,
+            Err(_) => {
+                different.push(name);
+
+                continue;
+            }
+        };
+        let output = emit_to_string(
+            &document,
+            CodegenConfig {
+                scripting_enabled: *scripting_enabled,
+                tag_omission,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        if output != *src {
+            different.push(name);
+        }
+    }
+
+    let identical = sources.len() - different.len();
+    let fidelity = identical as f64 * 100.0 / sources.len() as f64;
+
+    println!(
+        "round-trip fidelity: {:.2}% ({} of {})",
+        fidelity,
+        identical,
+        sources.len()
+    );
+
+    assert!(
+        fidelity >= min,
+        "round-trip fidelity dropped to {:.2}% ({} of {}), expected at least {:.2}%, printed \
+         differently:\n{}",
+        fidelity,
+        identical,
+        sources.len(),
+        min,
+        different
+            .iter()
+            .map(|name| name.as_str())
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+}
@@ -1,6 +1,7 @@
 #![allow(clippy::needless_update)]
 
 use std::{
+    io,
     mem::take,
     path::{Path, PathBuf},
 };
@@ -8,8 +9,9 @@ use std::{
 use swc_common::{FileName, Span};
 use swc_html_ast::*;
 use swc_html_codegen::{
+    emit_to_bytes, emit_to_string, emit_to_writer,
     writer::basic::{BasicHtmlWriter, BasicHtmlWriterConfig, IndentType, LineFeed},
-    CodeGenerator, CodegenConfig, Emit,
+    AttributeOrder, CdataStyle, CharEncoding, CodeGenerator, CodegenConfig, Emit, VoidElementStyle,
 };
 use swc_html_parser::{
     parse_file_as_document, parse_file_as_document_fragment, parser::ParserConfig,
@@ -22,6 +24,18 @@ fn print_document(
     parser_config: Option<ParserConfig>,
     writer_config: Option<BasicHtmlWriterConfig>,
     codegen_config: Option<CodegenConfig>,
+) {
+    print_document_with(input, parser_config, writer_config, codegen_config, true);
+}
+
+/// `compare_reparsed` should be `false` for options which change the document,
+/// i.e. the order of attributes or the content of scripts
+fn print_document_with(
+    input: &Path,
+    parser_config: Option<ParserConfig>,
+    writer_config: Option<BasicHtmlWriterConfig>,
+    codegen_config: Option<CodegenConfig>,
+    compare_reparsed: bool,
 ) {
     let dir = input.parent().unwrap();
     let parser_config = match parser_config {
@@ -80,10 +94,12 @@ fn print_document(
             error.to_diagnostics(&handler).emit();
         }
 
-        document.visit_mut_with(&mut DropSpan);
-        document_parsed_again.visit_mut_with(&mut DropSpan);
+        if compare_reparsed {
+            document.visit_mut_with(&mut DropSpan);
+            document_parsed_again.visit_mut_with(&mut DropSpan);
 
-        assert_eq!(document, document_parsed_again);
+            assert_eq!(document, document_parsed_again);
+        }
 
         Ok(())
     })
@@ -455,6 +471,114 @@ fn test_self_closing_void_elements_false(input: PathBuf) {
     );
 }
 
+#[testing::fixture("tests/options/void_element_style/xhtml/**/input.html")]
+fn test_void_element_style_xhtml(input: PathBuf) {
+    print_document(
+        &input,
+        None,
+        None,
+        Some(CodegenConfig {
+            scripting_enabled: false,
+            minify: false,
+            void_element_style: VoidElementStyle::Xhtml,
+            ..Default::default()
+        }),
+    );
+}
+
+#[testing::fixture("tests/options/cdata_script_style/comment/**/input.html")]
+fn test_cdata_script_style_comment(input: PathBuf) {
+    print_document_with(
+        &input,
+        None,
+        None,
+        Some(CodegenConfig {
+            scripting_enabled: false,
+            minify: false,
+            cdata_script_style: CdataStyle::Comment,
+            ..Default::default()
+        }),
+        false,
+    );
+}
+
+#[testing::fixture("tests/options/cdata_script_style/cdata/**/input.html")]
+fn test_cdata_script_style_cdata(input: PathBuf) {
+    print_document(
+        &input,
+        None,
+        None,
+        Some(CodegenConfig {
+            scripting_enabled: false,
+            minify: false,
+            cdata_script_style: CdataStyle::Cdata,
+            ..Default::default()
+        }),
+    );
+}
+
+#[testing::fixture("tests/options/char_encoding/ascii_safe/**/input.html")]
+fn test_char_encoding_ascii_safe(input: PathBuf) {
+    print_document(
+        &input,
+        None,
+        None,
+        Some(CodegenConfig {
+            scripting_enabled: false,
+            minify: false,
+            char_encoding: CharEncoding::AsciiSafe,
+            ..Default::default()
+        }),
+    );
+}
+
+#[testing::fixture("tests/options/tag_omission/**/input.html")]
+fn test_tag_omission(input: PathBuf) {
+    print_document(
+        &input,
+        None,
+        None,
+        Some(CodegenConfig {
+            scripting_enabled: false,
+            minify: false,
+            tag_omission: Some(true),
+            ..Default::default()
+        }),
+    );
+}
+
+#[testing::fixture("tests/options/attribute_order/alphabetical/**/input.html")]
+fn test_attribute_order_alphabetical(input: PathBuf) {
+    print_document_with(
+        &input,
+        None,
+        None,
+        Some(CodegenConfig {
+            scripting_enabled: false,
+            minify: false,
+            emit_attribute_order: AttributeOrder::Alphabetical,
+            ..Default::default()
+        }),
+        false,
+    );
+}
+
+#[testing::fixture("tests/options/attribute_order/canonical/**/input.html")]
+fn test_attribute_order_canonical(input: PathBuf) {
+    print_document_with(
+        &input,
+        None,
+        None,
+        Some(CodegenConfig {
+            scripting_enabled: false,
+            minify: false,
+            emit_attribute_order: AttributeOrder::Canonical,
+            ..Default::default()
+        }),
+        false,
+    );
+}
+
 #[testing::fixture("tests/options/quotes/true/**/input.html")]
 fn test_quotes_true(input: PathBuf) {
     print_document(
@@ -755,3 +879,42 @@ fn html5lib_tests_verify(input: PathBuf) {
         }
     }
 }
+
+#[test]
+fn emit_to_string_bytes_and_writer() {
+    run_test2(false, |cm, _handler| {
+        let fm = cm.new_source_file(
+            FileName::Anon,
+            "<!doctype html><html><head></head><body><p>test</p></body></html>".into(),
+        );
+        let document = parse_file_as_document(&fm, Default::default(), &mut vec![]).unwrap();
+
+        let code = emit_to_string(&document, CodegenConfig::default()).unwrap();
+
+        assert_eq!(
+            code,
+            "<!DOCTYPE html><html><head></head><body><p>test</p></body></html>"
+        );
+
+        let bytes = emit_to_bytes(&document, CodegenConfig::default()).unwrap();
+
+        assert_eq!(bytes, code.as_bytes());
+
+        let mut buf = vec![];
+
+        emit_to_writer(&document, CodegenConfig::default(), &mut buf).unwrap();
+
+        assert_eq!(buf, code.as_bytes());
+
+        // The output is streamed, so the start of the document is written before
+        // the writer fails
+        let mut small = [0; 8];
+        let err = emit_to_writer(&document, CodegenConfig::default(), &mut small[..]).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+        assert_eq!(&small[..], &code.as_bytes()[..8]);
+
+        Ok(())
+    })
+    .unwrap();
+}
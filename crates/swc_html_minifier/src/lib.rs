@@ -2338,6 +2338,10 @@ impl Minifier<'_> {
                 tag_omission: None,
                 self_closing_void_elements: None,
                 quotes: None,
+                void_element_style: Default::default(),
+                emit_attribute_order: Default::default(),
+                cdata_script_style: Default::default(),
+                char_encoding: Default::default(),
             },
         );
 
@@ -1,6 +1,8 @@
 extern crate swc_node_base;
 
-use criterion::{black_box, criterion_group, criterion_main, Bencher, Criterion};
+use criterion::{
+    black_box, criterion_group, criterion_main, Bencher, BenchmarkGroup, Criterion, Throughput,
+};
 use swc_common::{input::StringInput, FileName};
 use swc_html_parser::lexer::Lexer;
 
@@ -20,18 +22,40 @@ fn bench_lexer(b: &mut Bencher, src: &'static str) {
     });
 }
 
-fn bench_files(c: &mut Criterion) {
-    c.bench_function("html/lexer/css_2021_spec", |b| {
-        bench_lexer(b, include_str!("./files/css_2021_spec.html"))
-    });
-
-    c.bench_function("html/lexer/github_com_17_05_2022", |b| {
-        bench_lexer(b, include_str!("./files/github_com_17_05_2022.html"))
-    });
+fn bench_file<M>(group: &mut BenchmarkGroup<M>, name: &str, src: &'static str)
+where
+    M: criterion::measurement::Measurement,
+{
+    // Report throughput (i.e. MB/s) so inputs of different size are comparable
+    group.throughput(Throughput::Bytes(src.len() as u64));
+    group.bench_function(name, |b| bench_lexer(b, src));
+}
 
-    c.bench_function("html/lexer/stackoverflow_com_17_05_2022", |b| {
-        bench_lexer(b, include_str!("./files/stackoverflow_com_17_05_2022.html"))
-    });
+fn bench_files(c: &mut Criterion) {
+    let mut group = c.benchmark_group("html/lexer");
+
+    bench_file(
+        &mut group,
+        "small_snippet",
+        include_str!("./files/small_snippet.html"),
+    );
+    bench_file(
+        &mut group,
+        "css_2021_spec",
+        include_str!("./files/css_2021_spec.html"),
+    );
+    bench_file(
+        &mut group,
+        "github_com_17_05_2022",
+        include_str!("./files/github_com_17_05_2022.html"),
+    );
+    bench_file(
+        &mut group,
+        "stackoverflow_com_17_05_2022",
+        include_str!("./files/stackoverflow_com_17_05_2022.html"),
+    );
+
+    group.finish();
 }
 
 criterion_group!(benches, bench_files);
@@ -0,0 +1,260 @@
+//! [Determining the character encoding](https://html.spec.whatwg.org/multipage/parsing.html#determining-the-character-encoding)
+//! of a byte stream.
+
+pub use encoding_rs::Encoding;
+use encoding_rs::{UTF_16BE, UTF_16LE, UTF_8, WINDOWS_1252, X_USER_DEFINED};
+
+/// The number of bytes examined by the prescan.
+const PRESCAN_LIMIT: usize = 1024;
+
+/// Returns the encoding of `bytes` from the byte order mark, the
+/// `<meta charset>` or `<meta http-equiv="content-type">` declaration in the
+/// first 1024 bytes, or UTF-8.
+pub fn sniff_encoding(bytes: &[u8]) -> &'static Encoding {
+    if let Some((encoding, _)) = Encoding::for_bom(bytes) {
+        return encoding;
+    }
+
+    prescan(&bytes[..bytes.len().min(PRESCAN_LIMIT)]).unwrap_or(UTF_8)
+}
+
+// https://html.spec.whatwg.org/multipage/parsing.html#prescan-a-byte-stream-to-determine-its-encoding
+fn prescan(bytes: &[u8]) -> Option<&'static Encoding> {
+    let mut pos = 0;
+
+    while pos < bytes.len() {
+        let rest = &bytes[pos..];
+
+        if rest.starts_with(b"<!--") {
+            pos += find(&rest[2..], b"-->")? + 2 + 3;
+
+            continue;
+        }
+
+        if starts_with_ignore_ascii_case(rest, b"<meta")
+            && matches!(rest.get(5), Some(b) if is_whitespace(*b) || *b == b'/')
+        {
+            pos += 5;
+
+            if let Some(encoding) = prescan_meta(bytes, &mut pos)? {
+                return Some(encoding);
+            }
+
+            continue;
+        }
+
+        let is_tag = match rest {
+            [b'<', b, ..] if b.is_ascii_alphabetic() => Some(1),
+            [b'<', b'/', b, ..] if b.is_ascii_alphabetic() => Some(2),
+            _ => None,
+        };
+
+        if let Some(offset) = is_tag {
+            pos += offset;
+
+            while pos < bytes.len() && !is_whitespace(bytes[pos]) && bytes[pos] != b'>' {
+                pos += 1;
+            }
+
+            while get_attribute(bytes, &mut pos)?.is_some() {}
+        } else if rest.starts_with(b"<!") || rest.starts_with(b"</") || rest.starts_with(b"<?") {
+            pos += find(rest, b">")?;
+        }
+
+        pos += 1;
+    }
+
+    None
+}
+
+// Returns `Some(None)` when the `<meta>` doesn't declare an encoding
+fn prescan_meta(bytes: &[u8], pos: &mut usize) -> Option<Option<&'static Encoding>> {
+    let mut attribute_names = vec![];
+    let mut got_pragma = false;
+    let mut need_pragma = None;
+    let mut charset = None;
+
+    while let Some((name, value)) = get_attribute(bytes, pos)? {
+        if attribute_names.contains(&name) {
+            continue;
+        }
+
+        match &*name {
+            b"http-equiv" => {
+                if value == b"content-type" {
+                    got_pragma = true;
+                }
+            }
+            b"content" => {
+                if charset.is_none() {
+                    if let Some(encoding) = extract_encoding_from_content(&value) {
+                        charset = Some(encoding);
+                        need_pragma = Some(true);
+                    }
+                }
+            }
+            b"charset" => {
+                if charset.is_none() {
+                    charset = Encoding::for_label(&value);
+                    need_pragma = Some(false);
+                }
+            }
+            _ => {}
+        }
+
+        attribute_names.push(name);
+    }
+
+    match need_pragma {
+        Some(true) if !got_pragma => return Some(None),
+        None => return Some(None),
+        _ => {}
+    }
+
+    Some(charset.map(|charset| {
+        if charset == UTF_16BE || charset == UTF_16LE {
+            UTF_8
+        } else if charset == X_USER_DEFINED {
+            WINDOWS_1252
+        } else {
+            charset
+        }
+    }))
+}
+
+// https://html.spec.whatwg.org/multipage/parsing.html#concept-get-attributes-when-sniffing
+//
+// Returns `None` when the end of input is reached, and `Some(None)` when there
+// are no more attributes.
+#[allow(clippy::type_complexity)]
+fn get_attribute(bytes: &[u8], pos: &mut usize) -> Option<Option<(Vec<u8>, Vec<u8>)>> {
+    while is_whitespace(*bytes.get(*pos)?) || bytes[*pos] == b'/' {
+        *pos += 1;
+    }
+
+    if bytes[*pos] == b'>' {
+        return Some(None);
+    }
+
+    let mut name = vec![];
+    let mut value = vec![];
+
+    loop {
+        match *bytes.get(*pos)? {
+            b'=' if !name.is_empty() => {
+                *pos += 1;
+
+                break;
+            }
+            b if is_whitespace(b) => {
+                while is_whitespace(*bytes.get(*pos)?) {
+                    *pos += 1;
+                }
+
+                if bytes[*pos] != b'=' {
+                    return Some(Some((name, value)));
+                }
+
+                *pos += 1;
+
+                break;
+            }
+            b'/' | b'>' => return Some(Some((name, value))),
+            b => {
+                name.push(b.to_ascii_lowercase());
+
+                *pos += 1;
+            }
+        }
+    }
+
+    while is_whitespace(*bytes.get(*pos)?) {
+        *pos += 1;
+    }
+
+    match *bytes.get(*pos)? {
+        quote @ (b'"' | b'\'') => {
+            *pos += 1;
+
+            loop {
+                let b = *bytes.get(*pos)?;
+
+                *pos += 1;
+
+                if b == quote {
+                    return Some(Some((name, value)));
+                }
+
+                value.push(b.to_ascii_lowercase());
+            }
+        }
+        b'>' => Some(Some((name, value))),
+        _ => loop {
+            let b = *bytes.get(*pos)?;
+
+            if is_whitespace(b) || b == b'>' {
+                return Some(Some((name, value)));
+            }
+
+            value.push(b.to_ascii_lowercase());
+
+            *pos += 1;
+        },
+    }
+}
+
+// https://html.spec.whatwg.org/multipage/urls-and-fetching.html#algorithm-for-extracting-a-character-encoding-from-a-meta-element
+fn extract_encoding_from_content(content: &[u8]) -> Option<&'static Encoding> {
+    let mut pos = 0;
+
+    loop {
+        pos += find(&content[pos..], b"charset")? + 7;
+
+        while matches!(content.get(pos), Some(b) if is_whitespace(*b)) {
+            pos += 1;
+        }
+
+        if content.get(pos) == Some(&b'=') {
+            pos += 1;
+
+            break;
+        }
+    }
+
+    while matches!(content.get(pos), Some(b) if is_whitespace(*b)) {
+        pos += 1;
+    }
+
+    let rest = &content[pos..];
+    let label = match rest.first()? {
+        quote @ (b'"' | b'\'') => {
+            let end = rest[1..].iter().position(|b| b == quote)?;
+
+            &rest[1..end + 1]
+        }
+        _ => {
+            let end = rest
+                .iter()
+                .position(|b| is_whitespace(*b) || *b == b';')
+                .unwrap_or(rest.len());
+
+            &rest[..end]
+        }
+    };
+
+    Encoding::for_label(label)
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+fn starts_with_ignore_ascii_case(bytes: &[u8], prefix: &[u8]) -> bool {
+    bytes.len() >= prefix.len() && bytes[..prefix.len()].eq_ignore_ascii_case(prefix)
+}
+
+fn is_whitespace(b: u8) -> bool {
+    matches!(b, b'\t' | b'\n' | b'\x0C' | b'\r' | b' ')
+}
@@ -30,6 +30,9 @@ impl Error {
     pub fn message(&self) -> Cow<'static, str> {
         match &self.inner.1 {
             ErrorKind::Eof => "Unexpected end of file".into(),
+            ErrorKind::InvalidResetPosition => "Cannot reset to a position outside of the \
+                                                consumed input or inside of a character"
+                .into(),
 
             // Lexer errors
             ErrorKind::AbruptClosingOfEmptyComment => "Abrupt closing of empty comment".into(),
@@ -262,6 +265,7 @@ impl Error {
 #[non_exhaustive]
 pub enum ErrorKind {
     Eof,
+    InvalidResetPosition,
 
     // Lexer errors
     AbruptClosingOfEmptyComment,
@@ -1,7 +1,11 @@
 use std::{cell::RefCell, char::REPLACEMENT_CHARACTER, collections::VecDeque, mem::take, rc::Rc};
 
 use swc_atoms::{Atom, JsWord};
-use swc_common::{collections::AHashSet, input::Input, BytePos, Span};
+use swc_common::{
+    collections::AHashSet,
+    input::{Input, StringInput},
+    BytePos, Span,
+};
 use swc_html_ast::{AttributeToken, Raw, Token, TokenAndSpan};
 use swc_html_utils::{Entity, HTML_ENTITIES};
 
@@ -136,12 +140,23 @@ pub(crate) type LexResult<T> = Result<T, ErrorKind>;
 
 // TODO improve `raw` for all tokens (linting + better codegen)
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LexerOptions {
+    /// Report `missing-semicolon-after-character-reference` for every named
+    /// character reference without a semicolon, including the ones in
+    /// attribute values which are kept as is for historical reasons, i.e.
+    /// `<a href="?a=b&copy=c">`. Disabled by default.
+    pub strict_entities: bool,
+}
+
 pub struct Lexer<I>
 where
     I: Input,
 {
     input: I,
+    options: LexerOptions,
     cur: Option<char>,
+    start_pos: BytePos,
     cur_pos: BytePos,
     last_token_pos: BytePos,
     finished: bool,
@@ -166,11 +181,17 @@ where
     I: Input,
 {
     pub fn new(input: I) -> Self {
+        Self::with_options(input, Default::default())
+    }
+
+    pub fn with_options(input: I, options: LexerOptions) -> Self {
         let start_pos = input.last_pos();
 
         let mut lexer = Lexer {
             input,
+            options,
             cur: None,
+            start_pos,
             cur_pos: start_pos,
             last_token_pos: start_pos,
             finished: false,
@@ -199,6 +220,62 @@ where
 
         lexer
     }
+
+    /// Switch the tokenizer to the given state, i.e. `State::Rawtext` after
+    /// `<style>` start tag.
+    pub fn set_state(&mut self, state: State) {
+        self.state = state;
+    }
+
+    /// Returns the current state of the tokenizer.
+    pub fn get_state(&self) -> &State {
+        &self.state
+    }
+
+    /// Returns the next token without consuming it, the token is returned by
+    /// the next call of [Iterator::next].
+    ///
+    /// The token is produced in the current state, so changing the state after
+    /// peeking doesn't affect the already peeked token.
+    pub fn peek_token(&mut self) -> Option<&TokenAndSpan> {
+        if self.finished {
+            return None;
+        }
+
+        while self.pending_tokens.is_empty() {
+            if self.run().is_err() {
+                return None;
+            }
+        }
+
+        match self.pending_tokens.front() {
+            Some(TokenAndSpan {
+                token: Token::Eof, ..
+            }) => None,
+            token_and_span => token_and_span,
+        }
+    }
+}
+
+impl<I> Lexer<I>
+where
+    I: Input,
+{
+    /// Returns `true` when no token is in progress, i.e. a new lexer created at
+    /// the current position produces the same tokens.
+    #[cfg(feature = "async")]
+    pub(crate) fn is_at_token_boundary(&self) -> bool {
+        matches!(self.state, State::Data) && self.pending_tokens.is_empty()
+    }
+}
+
+impl<'a> Lexer<StringInput<'a>> {
+    /// Returns the number of bytes which are not consumed yet.
+    ///
+    /// Can be used with the size of the source to report progress.
+    pub fn remaining_bytes(&self) -> usize {
+        self.input.as_str().len()
+    }
 }
 
 impl<I: Input> Iterator for Lexer<I> {
@@ -243,7 +320,59 @@ where
     }
 
     fn set_input_state(&mut self, state: State) {
-        self.state = state;
+        self.set_state(state);
+    }
+
+    fn reset_to(&mut self, pos: BytePos) -> Result<(), ErrorKind> {
+        let last_pos = self.input.last_pos();
+
+        if pos < self.start_pos || pos > last_pos {
+            return Err(ErrorKind::InvalidResetPosition);
+        }
+
+        // `Input` has no way to check char boundaries, so walk from the start
+        let mut end = self.start_pos;
+
+        self.input.reset_to(self.start_pos);
+        self.input.uncons_while(|c| {
+            if end < pos {
+                end = end + BytePos(c.len_utf8() as u32);
+
+                true
+            } else {
+                false
+            }
+        });
+
+        if end != pos {
+            self.input.reset_to(last_pos);
+
+            return Err(ErrorKind::InvalidResetPosition);
+        }
+
+        self.cur = None;
+        self.cur_pos = pos;
+        self.last_token_pos = pos;
+        self.finished = false;
+        self.state = State::Data;
+        self.return_state = State::Data;
+        self.last_start_tag_name = None;
+        self.pending_tokens.clear();
+        self.current_doctype_token = None;
+        self.current_comment_token = None;
+        self.doctype_raw = None;
+        self.current_tag_token = None;
+        self.attribute_start_position = None;
+        self.character_reference_code = None;
+        self.temporary_buffer.clear();
+        self.is_adjusted_current_node_is_element_in_html_namespace = None;
+        self.char_buf.borrow_mut().clear();
+
+        if self.input.is_at_start() && self.input.cur() == Some('\u{feff}') {
+            self.input.bump();
+        }
+
+        Ok(())
     }
 }
 
@@ -4133,6 +4262,10 @@ where
                             && !is_last_semicolon
                             && is_next_equals_sign_or_ascii_alphanumeric
                         {
+                            if self.options.strict_entities {
+                                self.emit_error(ErrorKind::MissingSemicolonAfterCharacterReference);
+                            }
+
                             self.flush_code_points_consumed_as_character_reference(None);
                             self.state = self.return_state.clone();
                         }
@@ -6,10 +6,12 @@
 #![allow(clippy::wrong_self_convention)]
 #![allow(clippy::match_like_matches_macro)]
 
-use swc_common::{input::StringInput, SourceFile};
+use swc_common::{input::StringInput, BytePos, SourceFile};
 use swc_html_ast::{Document, DocumentFragment, DocumentMode, Element};
 
+pub use crate::sax::{parse_html_sax, HtmlHandler};
 use crate::{
+    encoding::{sniff_encoding, Encoding},
     error::Error,
     lexer::Lexer,
     parser::{PResult, Parser, ParserConfig},
@@ -17,9 +19,14 @@ use crate::{
 
 #[macro_use]
 mod macros;
+pub mod encoding;
 pub mod error;
 pub mod lexer;
 pub mod parser;
+pub mod sax;
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub mod streaming;
 
 /// Parse a given file as `Document`.
 ///
@@ -39,6 +46,34 @@ pub fn parse_file_as_document(
     result
 }
 
+/// Parse bytes as `Document`, the encoding is detected with
+/// [sniff_encoding] and returned with the document.
+///
+/// Spans are byte offsets in the decoded source. Invalid byte sequences are
+/// replaced with U+FFFD.
+///
+/// If there are syntax errors but if it was recoverable, it will be appended to
+/// `errors`.
+pub fn parse_document_bytes(
+    bytes: &[u8],
+    config: ParserConfig,
+    errors: &mut Vec<Error>,
+) -> PResult<(Document, &'static Encoding)> {
+    let encoding = sniff_encoding(bytes);
+    let (src, _) = encoding.decode_with_bom_removal(bytes);
+    let lexer = Lexer::new(StringInput::new(
+        &src,
+        BytePos(0),
+        BytePos(src.len() as u32),
+    ));
+    let mut parser = Parser::new(lexer, config);
+    let result = parser.parse_document();
+
+    errors.extend(parser.take_errors());
+
+    result.map(|document| (document, encoding))
+}
+
 /// Parse a given file as `DocumentFragment`.
 ///
 /// If there are syntax errors but if it was recoverable, it will be appended to
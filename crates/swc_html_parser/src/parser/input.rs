@@ -5,7 +5,10 @@ use swc_common::{BytePos, Span};
 use swc_html_ast::{Token, TokenAndSpan};
 
 use super::PResult;
-use crate::{error::Error, lexer::State};
+use crate::{
+    error::{Error, ErrorKind},
+    lexer::State,
+};
 
 pub trait ParserInput: Iterator<Item = TokenAndSpan> {
     fn start_pos(&mut self) -> BytePos;
@@ -19,6 +22,10 @@ pub trait ParserInput: Iterator<Item = TokenAndSpan> {
     fn set_input_state(&mut self, state: State);
 
     fn set_adjusted_current_node_to_html_namespace(&mut self, value: bool);
+
+    /// Returns [ErrorKind::InvalidResetPosition] when `pos` is not a valid
+    /// position, the input is left unchanged in this case.
+    fn reset_to(&mut self, pos: BytePos) -> Result<(), ErrorKind>;
 }
 
 #[derive(Debug)]
@@ -104,4 +111,13 @@ where
         self.input
             .set_adjusted_current_node_to_html_namespace(value);
     }
+
+    pub(super) fn reset_to(&mut self, pos: BytePos) -> PResult<()> {
+        self.input
+            .reset_to(pos)
+            .map_err(|kind| Error::new(Span::new(pos, pos, Default::default()), kind))?;
+        self.cur = None;
+
+        Ok(())
+    }
 }
@@ -5,7 +5,7 @@ use doctypes::*;
 use node::*;
 use open_elements_stack::*;
 use swc_atoms::{js_word, Atom, JsWord};
-use swc_common::{Span, DUMMY_SP};
+use swc_common::{BytePos, Span, DUMMY_SP};
 use swc_html_ast::*;
 
 use self::input::{Buffer, ParserInput};
@@ -26,6 +26,10 @@ pub type PResult<T> = Result<T, Error>;
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ParserConfig {
+    /// The [scripting flag](https://html.spec.whatwg.org/multipage/parsing.html#scripting-flag).
+    ///
+    /// When enabled, the content of `<noscript>` is parsed as raw text,
+    /// otherwise it is parsed as HTML. Disabled by default.
     pub scripting_enabled: bool,
     pub iframe_srcdoc: bool,
 }
@@ -40,8 +44,10 @@ enum AdjustAttributes {
     Svg,
 }
 
-#[derive(Debug, Clone)]
-enum InsertionMode {
+/// The [insertion mode](https://html.spec.whatwg.org/multipage/parsing.html#insertion-mode)
+/// of the tree construction stage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InsertionMode {
     Initial,
     BeforeHtml,
     BeforeHead,
@@ -92,6 +98,7 @@ where
     original_insertion_mode: InsertionMode,
     template_insertion_mode_stack: Vec<InsertionMode>,
     document: Option<RcNode>,
+    document_mode: DocumentMode,
     head_element_pointer: Option<RcNode>,
     form_element_pointer: Option<RcNode>,
     open_elements_stack: OpenElementsStack,
@@ -117,6 +124,7 @@ where
             original_insertion_mode: Default::default(),
             template_insertion_mode_stack: Vec::with_capacity(16),
             document: None,
+            document_mode: DocumentMode::NoQuirks,
             head_element_pointer: None,
             form_element_pointer: None,
             open_elements_stack: OpenElementsStack::new(),
@@ -136,6 +144,73 @@ where
         mem::take(&mut self.errors)
     }
 
+    /// Returns the quirks mode of the document being parsed or of the last
+    /// parsed document, i.e. the value of [Document::mode].
+    ///
+    /// For fragments, this is the mode passed to
+    /// [Parser::parse_document_fragment].
+    pub fn quirks_mode(&self) -> DocumentMode {
+        match &self.document {
+            Some(document) => get_document_mode!(document),
+            None => self.document_mode,
+        }
+    }
+
+    /// Returns the current insertion mode of the tree builder, useful for
+    /// debugging, i.e. after parsing a truncated document.
+    pub fn current_insertion_mode(&self) -> InsertionMode {
+        self.insertion_mode.clone()
+    }
+
+    /// Returns the tag names of the elements in the stack of open elements,
+    /// from the root element to the current node.
+    ///
+    /// Parsing stops at the end of the input without popping the stack, so
+    /// the stack shows which elements were left unclosed.
+    pub fn open_element_stack(&self) -> Vec<JsWord> {
+        self.open_elements_stack
+            .items
+            .iter()
+            .map(|node| get_tag_name!(node).clone())
+            .collect()
+    }
+
+    /// Resets the parser to `pos`, so the source can be parsed again from a
+    /// known clean position without creating a new parser, i.e. in linters
+    /// after fixing an error.
+    ///
+    /// All in-progress tokens and the state of the tree builder are dropped,
+    /// errors are kept until [Parser::take_errors] is called.
+    ///
+    /// # Errors
+    ///
+    /// Returns [ErrorKind::InvalidResetPosition] if `pos` is before the start
+    /// of the input, ahead of the current position or inside of a character,
+    /// the parser is left unchanged in this case.
+    ///
+    /// The position is checked by walking the input from the start, so the
+    /// cost is linear in `pos`.
+    pub fn reset_to(&mut self, pos: BytePos) -> PResult<()> {
+        self.input.reset_to(pos)?;
+        self.stopped = false;
+        self.is_fragment_case = false;
+        self.context_element = None;
+        self.insertion_mode = Default::default();
+        self.original_insertion_mode = Default::default();
+        self.template_insertion_mode_stack.clear();
+        self.document = None;
+        self.document_mode = DocumentMode::NoQuirks;
+        self.head_element_pointer = None;
+        self.form_element_pointer = None;
+        self.open_elements_stack = OpenElementsStack::new();
+        self.active_formatting_elements = ActiveFormattingElementStack::new();
+        self.pending_character_tokens.clear();
+        self.frameset_ok = true;
+        self.foster_parenting_enabled = false;
+
+        Ok(())
+    }
+
     pub fn parse_document(&mut self) -> PResult<Document> {
         let start = self.input.cur_span()?;
 
@@ -159,6 +234,8 @@ where
             }
         };
 
+        self.document_mode = mode;
+
         Ok(Document {
             span: Span::new(start.lo(), last, Default::default()),
             mode,
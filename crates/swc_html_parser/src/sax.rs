@@ -0,0 +1,74 @@
+use swc_atoms::js_word;
+use swc_common::{input::StringInput, BytePos};
+use swc_html_ast::{Token, TokenAndSpan};
+
+use crate::lexer::{Lexer, State};
+
+/// Receives tokens from [parse_html_sax] in the source order.
+pub trait HtmlHandler {
+    fn start_tag(&mut self, tag: &TokenAndSpan);
+
+    fn end_tag(&mut self, tag: &TokenAndSpan);
+
+    /// Called for every character token.
+    fn text(&mut self, token: &TokenAndSpan);
+
+    fn comment(&mut self, token: &TokenAndSpan);
+
+    fn doctype(&mut self, token: &TokenAndSpan);
+}
+
+/// Tokenize `src` and pass tokens to `handler` without building a tree.
+///
+/// Spans are byte offsets in `src`.
+///
+/// There is no tree construction, so the tokenizer state is switched only
+/// based on the tag name of the last start tag, i.e. the content of `<script>`
+/// and `<style>` is reported as text, but `<script>` inside `<svg>` is
+/// tokenized as the script data too. Errors are ignored.
+pub fn parse_html_sax<H: HtmlHandler>(src: &str, handler: &mut H) {
+    let mut lexer = Lexer::new(StringInput::new(src, BytePos(0), BytePos(src.len() as u32)));
+
+    while let Some(token_and_span) = lexer.next() {
+        match &token_and_span.token {
+            Token::StartTag { tag_name, .. } => {
+                match *tag_name {
+                    js_word!("title") | js_word!("textarea") => {
+                        lexer.set_state(State::Rcdata);
+                    }
+                    js_word!("style")
+                    | js_word!("xmp")
+                    | js_word!("iframe")
+                    | js_word!("noembed")
+                    | js_word!("noframes") => {
+                        lexer.set_state(State::Rawtext);
+                    }
+                    js_word!("script") => {
+                        lexer.set_state(State::ScriptData);
+                    }
+                    js_word!("plaintext") => {
+                        lexer.set_state(State::PlainText);
+                    }
+                    _ => {}
+                }
+
+                handler.start_tag(&token_and_span);
+            }
+            Token::EndTag { .. } => {
+                handler.end_tag(&token_and_span);
+            }
+            Token::Character { .. } => {
+                handler.text(&token_and_span);
+            }
+            Token::Comment { .. } => {
+                handler.comment(&token_and_span);
+            }
+            Token::Doctype { .. } => {
+                handler.doctype(&token_and_span);
+            }
+            Token::Eof => {
+                break;
+            }
+        }
+    }
+}
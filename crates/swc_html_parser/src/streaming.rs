@@ -0,0 +1,301 @@
+//! Tokenizing HTML which arrives in chunks, i.e. from a network connection.
+
+use std::{
+    cell::Cell,
+    collections::VecDeque,
+    mem::take,
+    pin::Pin,
+    rc::Rc,
+    str,
+    task::{Context, Poll},
+};
+
+use bytes::Bytes;
+use futures_core::{ready, Stream};
+use pin_project::pin_project;
+use swc_common::{
+    input::{Input, StringInput},
+    BytePos,
+};
+use swc_html_ast::TokenAndSpan;
+
+use crate::{error::Error, lexer::Lexer, parser::input::ParserInput};
+
+/// Tokenizes UTF-8 chunks of HTML from `stream`, tokens are emitted as soon as
+/// they can't be changed by the following chunks.
+///
+/// Spans are byte offsets in the concatenated chunks.
+///
+/// Like [Lexer], the tokenizer state is not switched for the content of
+/// `<script>`, `<style>` and etc, because there is no tree construction.
+///
+/// Only the text after the last complete token is kept, when the next chunk
+/// arrives tokenizing resumes from there. A single token spanning many chunks,
+/// i.e. a long comment, is tokenized again for every chunk.
+#[pin_project]
+pub struct StreamingLexer<S> {
+    #[pin]
+    stream: S,
+    state: StreamingState,
+}
+
+impl<S> StreamingLexer<S>
+where
+    S: Stream<Item = Bytes>,
+{
+    pub fn new(stream: S) -> Self {
+        StreamingLexer {
+            stream,
+            state: Default::default(),
+        }
+    }
+}
+
+impl<S> Stream for StreamingLexer<S>
+where
+    S: Stream<Item = Bytes>,
+{
+    type Item = Result<TokenAndSpan, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            if let Some(item) = this.state.pending.pop_front() {
+                return Poll::Ready(Some(item));
+            }
+
+            if this.state.is_finished {
+                return Poll::Ready(None);
+            }
+
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(chunk) => {
+                    this.state.push_chunk(&chunk);
+                    this.state.tokenize(false);
+                }
+                None => {
+                    // Incomplete UTF-8 sequence at the end of input
+                    if !this.state.bytes.is_empty() {
+                        let bytes = take(&mut this.state.bytes);
+
+                        this.state.text.push_str(&String::from_utf8_lossy(&bytes));
+                    }
+
+                    this.state.tokenize(true);
+                    this.state.is_finished = true;
+                }
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct StreamingState {
+    /// Bytes of an incomplete UTF-8 sequence at the end of the last chunk
+    bytes: Vec<u8>,
+    /// The text which is not tokenized yet, starting at `offset`
+    text: String,
+    offset: u32,
+    pending: VecDeque<Result<TokenAndSpan, Error>>,
+    is_finished: bool,
+}
+
+impl StreamingState {
+    fn push_chunk(&mut self, chunk: &[u8]) {
+        self.bytes.extend_from_slice(chunk);
+
+        let mut rest = &*take(&mut self.bytes);
+
+        loop {
+            match str::from_utf8(rest) {
+                Ok(valid) => {
+                    self.text.push_str(valid);
+
+                    break;
+                }
+                Err(err) => {
+                    let (valid, invalid) = rest.split_at(err.valid_up_to());
+
+                    // Safety: `valid_up_to` is the length of the valid prefix
+                    self.text
+                        .push_str(unsafe { str::from_utf8_unchecked(valid) });
+
+                    match err.error_len() {
+                        Some(len) => {
+                            self.text.push(char::REPLACEMENT_CHARACTER);
+
+                            rest = &invalid[len..];
+                        }
+                        None => {
+                            self.bytes = invalid.to_vec();
+
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn tokenize(&mut self, is_last: bool) {
+        let reached_end = Rc::new(Cell::new(false));
+        let input = StringInput::new(
+            &self.text,
+            BytePos(self.offset),
+            BytePos(self.offset + self.text.len() as u32),
+        );
+        let mut lexer = Lexer::new(ChunkInput {
+            input,
+            is_document_start: self.offset == 0,
+            reached_end: if is_last {
+                None
+            } else {
+                Some(reached_end.clone())
+            },
+        });
+        // Items since the last token boundary, they are dropped when the lexer
+        // reaches the end of the current input before the next boundary
+        let mut items = vec![];
+        let mut resume_pos = None;
+
+        loop {
+            let token_and_span = lexer.next();
+
+            // The token depends on the end of the current input, so it can be
+            // different when the next chunk arrives
+            if reached_end.get() {
+                break;
+            }
+
+            items.extend(lexer.take_errors().into_iter().map(Err));
+
+            let is_done = token_and_span.is_none();
+
+            if let Some(token_and_span) = token_and_span {
+                items.push(Ok(token_and_span));
+            }
+
+            if is_done || lexer.is_at_token_boundary() {
+                self.pending.extend(items.drain(..));
+
+                resume_pos = Some(lexer.start_pos());
+            }
+
+            if is_done {
+                break;
+            }
+        }
+
+        if let Some(BytePos(resume_pos)) = resume_pos {
+            self.text.drain(..(resume_pos - self.offset) as usize);
+            self.offset = resume_pos;
+        }
+    }
+}
+
+/// [StringInput] which records when the lexer looks past the end of the
+/// current input.
+#[derive(Clone)]
+struct ChunkInput<'a> {
+    input: StringInput<'a>,
+    /// `false` when tokenizing resumes in the middle of the document
+    is_document_start: bool,
+    reached_end: Option<Rc<Cell<bool>>>,
+}
+
+impl ChunkInput<'_> {
+    fn check<T>(&self, value: Option<T>) -> Option<T> {
+        if value.is_none() {
+            self.mark_end();
+        }
+
+        value
+    }
+
+    fn mark_end(&self) {
+        if let Some(reached_end) = &self.reached_end {
+            reached_end.set(true);
+        }
+    }
+}
+
+impl Input for ChunkInput<'_> {
+    fn cur(&mut self) -> Option<char> {
+        let cur = self.input.cur();
+
+        self.check(cur)
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        let peek = self.input.peek();
+
+        self.check(peek)
+    }
+
+    fn peek_ahead(&mut self) -> Option<char> {
+        let peek_ahead = self.input.peek_ahead();
+
+        self.check(peek_ahead)
+    }
+
+    fn bump(&mut self) {
+        self.input.bump();
+    }
+
+    fn is_at_start(&self) -> bool {
+        // Don't skip `U+FEFF` as BOM after resuming
+        self.is_document_start && self.input.is_at_start()
+    }
+
+    fn cur_pos(&mut self) -> BytePos {
+        self.input.cur_pos()
+    }
+
+    fn last_pos(&self) -> BytePos {
+        self.input.last_pos()
+    }
+
+    fn slice(&mut self, start: BytePos, end: BytePos) -> &str {
+        self.input.slice(start, end)
+    }
+
+    fn uncons_while<F>(&mut self, f: F) -> &str
+    where
+        F: FnMut(char) -> bool,
+    {
+        let start = self.input.cur_pos();
+
+        self.input.uncons_while(f);
+
+        let end = self.input.cur_pos();
+
+        if self.input.as_str().is_empty() {
+            self.mark_end();
+        }
+
+        self.input.slice(start, end)
+    }
+
+    fn find<F>(&mut self, f: F) -> Option<BytePos>
+    where
+        F: FnMut(char) -> bool,
+    {
+        let pos = self.input.find(f);
+
+        self.check(pos)
+    }
+
+    fn reset_to(&mut self, to: BytePos) {
+        self.input.reset_to(to);
+    }
+
+    fn is_str(&self, s: &str) -> bool {
+        // The answer can change when the rest of `s` arrives
+        if self.input.as_str().len() < s.len() {
+            self.mark_end();
+        }
+
+        self.input.is_str(s)
+    }
+}
@@ -1,6 +1,10 @@
 use std::path::PathBuf;
 
-use swc_common::{errors::Handler, input::SourceFileInput, Spanned};
+use swc_common::{
+    errors::Handler,
+    input::{SourceFileInput, StringInput},
+    BytePos, Spanned,
+};
 use swc_html_ast::*;
 use swc_html_parser::{
     lexer::Lexer,
@@ -292,6 +296,29 @@ impl Visit for SpanVisualizer<'_> {
     mtd!(Comment, visit_comment);
 }
 
+/// Parses `input` as a document and compares the tree with `expected`, which
+/// is in the html5lib tree construction format.
+#[allow(dead_code)]
+pub fn document_dom_tree_test(input: &str, expected: &str) {
+    let lexer = Lexer::new(StringInput::new(
+        input,
+        BytePos(0),
+        BytePos(input.len() as u32),
+    ));
+    let mut parser = Parser::new(lexer, Default::default());
+    let document: PResult<Document> = parser.parse_document();
+    let mut document = document.expect("failed to parse document");
+    let mut dom_buf = String::new();
+
+    document.visit_mut_with(&mut DomVisualizer {
+        dom_buf: &mut dom_buf,
+        indent: 0,
+    });
+
+    testing::assert_eq!(dom_buf, expected);
+}
+
+#[allow(dead_code)]
 pub fn document_span_visualizer(input: PathBuf, config: ParserConfig, relative_to_file: bool) {
     let dir = input.parent().unwrap().to_path_buf();
 
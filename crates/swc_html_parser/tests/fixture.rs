@@ -7,7 +7,16 @@
 use std::path::PathBuf;
 
 use common::{document_dom_visualizer, document_span_visualizer};
-use swc_html_parser::parser::ParserConfig;
+use swc_common::{input::StringInput, BytePos};
+use swc_html_ast::{Child, Document, DocumentMode, Token, TokenAndSpan};
+use swc_html_parser::{
+    encoding::sniff_encoding,
+    error::ErrorKind,
+    lexer::{Lexer, LexerOptions},
+    parse_document_bytes, parse_html_sax,
+    parser::{input::ParserInput, InsertionMode, Parser, ParserConfig},
+    HtmlHandler,
+};
 
 use crate::common::document_test;
 
@@ -44,3 +53,414 @@ fn dom_visualizer(input: PathBuf) {
         },
     )
 }
+
+#[test]
+fn reset_to() {
+    let src = "<!doctype html><div>test</span></div>";
+    let lexer = Lexer::new(StringInput::new(src, BytePos(0), BytePos(src.len() as u32)));
+    let mut parser = Parser::new(lexer, Default::default());
+
+    let document: Document = parser.parse_document().unwrap();
+    let errors = parser.take_errors();
+
+    assert!(!errors.is_empty());
+
+    parser.reset_to(BytePos(0)).unwrap();
+
+    let document_parsed_again: Document = parser.parse_document().unwrap();
+
+    assert_eq!(document, document_parsed_again);
+    assert_eq!(errors, parser.take_errors());
+}
+
+#[test]
+fn reset_to_invalid_position() {
+    let src = "<!doctype html><p>caf\u{e9}</p>";
+    let lexer = Lexer::new(StringInput::new(
+        src,
+        BytePos(10),
+        BytePos(10 + src.len() as u32),
+    ));
+    let mut parser = Parser::new(lexer, Default::default());
+
+    // Ahead of the current position
+    let err = parser.reset_to(BytePos(15)).unwrap_err();
+
+    assert_eq!(*err.kind(), ErrorKind::InvalidResetPosition);
+
+    let document: Document = parser.parse_document().unwrap();
+
+    // Before the start of the input
+    let err = parser.reset_to(BytePos(5)).unwrap_err();
+
+    assert_eq!(*err.kind(), ErrorKind::InvalidResetPosition);
+
+    // Inside of `é`
+    let err = parser
+        .reset_to(BytePos(10 + src.find('\u{e9}').unwrap() as u32 + 1))
+        .unwrap_err();
+
+    assert_eq!(*err.kind(), ErrorKind::InvalidResetPosition);
+
+    parser.reset_to(BytePos(10)).unwrap();
+
+    let document_parsed_again: Document = parser.parse_document().unwrap();
+
+    assert_eq!(document, document_parsed_again);
+}
+
+#[test]
+fn quirks_mode() {
+    let cases = [
+        ("<!doctype html><p>test", DocumentMode::NoQuirks),
+        ("<p>test", DocumentMode::Quirks),
+        (
+            "<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 Transitional//EN\" \
+             \"http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd\"><p>test",
+            DocumentMode::LimitedQuirks,
+        ),
+    ];
+
+    for (src, expected) in cases {
+        let lexer = Lexer::new(StringInput::new(src, BytePos(0), BytePos(src.len() as u32)));
+        let mut parser = Parser::new(lexer, Default::default());
+
+        assert_eq!(parser.quirks_mode(), DocumentMode::NoQuirks);
+
+        let document: Document = parser.parse_document().unwrap();
+
+        assert_eq!(document.mode, expected, "{}", src);
+        assert_eq!(parser.quirks_mode(), expected, "{}", src);
+    }
+}
+
+#[test]
+fn insertion_mode_and_open_element_stack() {
+    let cases = [
+        (
+            "<!doctype html><p>test</p>",
+            InsertionMode::InBody,
+            vec!["html", "body"],
+        ),
+        (
+            "<!doctype html><table><tr><td>cell",
+            InsertionMode::InCell,
+            vec!["html", "body", "table", "tbody", "tr", "td"],
+        ),
+        (
+            "<!doctype html><select><option>test",
+            InsertionMode::InSelect,
+            vec!["html", "body", "select", "option"],
+        ),
+    ];
+
+    for (src, expected_mode, expected_stack) in cases {
+        let lexer = Lexer::new(StringInput::new(src, BytePos(0), BytePos(src.len() as u32)));
+        let mut parser = Parser::new(lexer, Default::default());
+
+        assert_eq!(parser.current_insertion_mode(), InsertionMode::Initial);
+        assert!(parser.open_element_stack().is_empty());
+
+        let _: Document = parser.parse_document().unwrap();
+
+        assert_eq!(parser.current_insertion_mode(), expected_mode, "{}", src);
+        assert_eq!(
+            parser
+                .open_element_stack()
+                .iter()
+                .map(|tag_name| &**tag_name)
+                .collect::<Vec<_>>(),
+            expected_stack,
+            "{}",
+            src
+        );
+    }
+}
+
+#[test]
+fn find_first_and_find_all() {
+    let src = "<!doctype html><html><head><meta charset=\"utf-8\"><meta name=\"description\" \
+               content=\"test\"></head><body><p>a<b>b</b></p><p>c</p></body></html>";
+    let lexer = Lexer::new(StringInput::new(src, BytePos(0), BytePos(src.len() as u32)));
+    let mut parser = Parser::new(lexer, Default::default());
+    let document: Document = parser.parse_document().unwrap();
+    let html = &document.children[1];
+
+    let description = html.find_first(|node| {
+        matches!(node, Child::Element(element) if &*element.tag_name == "meta"
+            && element.attributes.iter().any(|attribute| &*attribute.name == "name"))
+    });
+
+    assert!(matches!(
+        description,
+        Some(Child::Element(element)) if element.attributes.len() == 2
+    ));
+    assert_eq!(
+        html.find_first(|node| matches!(node, Child::Comment(_))),
+        None
+    );
+
+    let texts = html
+        .find_all(|node| node.is_text())
+        .into_iter()
+        .map(|node| match node {
+            Child::Text(text) => &*text.data,
+            _ => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(texts, vec!["a", "b", "c"]);
+}
+
+#[test]
+fn remaining_bytes() {
+    let src = "<!doctype html><div>test</div>";
+    let mut lexer = Lexer::new(StringInput::new(src, BytePos(0), BytePos(src.len() as u32)));
+
+    assert_eq!(lexer.remaining_bytes(), src.len());
+
+    lexer.next();
+
+    let remaining_bytes = lexer.remaining_bytes();
+
+    assert!(remaining_bytes > 0 && remaining_bytes < src.len());
+
+    for _ in lexer.by_ref() {}
+
+    assert_eq!(lexer.remaining_bytes(), 0);
+}
+
+#[test]
+fn peek_token() {
+    let src = "<div>a</div>";
+    let mut lexer = Lexer::new(StringInput::new(src, BytePos(0), BytePos(src.len() as u32)));
+
+    let peeked = lexer.peek_token().cloned();
+
+    assert!(matches!(
+        peeked,
+        Some(TokenAndSpan {
+            token: Token::StartTag { .. },
+            ..
+        })
+    ));
+    assert_eq!(lexer.peek_token().cloned(), peeked);
+    assert_eq!(lexer.next(), peeked);
+
+    assert!(matches!(
+        lexer.peek_token(),
+        Some(TokenAndSpan {
+            token: Token::Character { value: 'a', .. },
+            ..
+        })
+    ));
+
+    for _ in lexer.by_ref() {}
+
+    assert_eq!(lexer.peek_token(), None);
+}
+
+#[derive(Default)]
+struct EventCollector {
+    events: Vec<String>,
+    text: String,
+}
+
+impl EventCollector {
+    fn flush_text(&mut self) {
+        if !self.text.is_empty() {
+            let text = std::mem::take(&mut self.text);
+
+            self.events.push(format!("text {:?}", text));
+        }
+    }
+}
+
+impl HtmlHandler for EventCollector {
+    fn start_tag(&mut self, tag: &TokenAndSpan) {
+        self.flush_text();
+
+        if let Token::StartTag { tag_name, .. } = &tag.token {
+            self.events.push(format!(
+                "start {} {}..{}",
+                tag_name, tag.span.lo.0, tag.span.hi.0
+            ));
+        }
+    }
+
+    fn end_tag(&mut self, tag: &TokenAndSpan) {
+        self.flush_text();
+
+        if let Token::EndTag { tag_name, .. } = &tag.token {
+            self.events.push(format!("end {}", tag_name));
+        }
+    }
+
+    fn text(&mut self, token: &TokenAndSpan) {
+        if let Token::Character { value, .. } = &token.token {
+            self.text.push(*value);
+        }
+    }
+
+    fn comment(&mut self, token: &TokenAndSpan) {
+        self.flush_text();
+
+        if let Token::Comment { data, .. } = &token.token {
+            self.events.push(format!("comment {:?}", data));
+        }
+    }
+
+    fn doctype(&mut self, token: &TokenAndSpan) {
+        self.flush_text();
+
+        if let Token::Doctype { name, .. } = &token.token {
+            self.events.push(format!("doctype {:?}", name.as_deref()));
+        }
+    }
+}
+
+#[test]
+fn parse_html_sax_events() {
+    let src = "<!doctype html><title>a<b</title><!--c--><script>if (a<b) {}</script><p \
+               class=\"d\">e&amp;f</p>";
+    let mut handler = EventCollector::default();
+
+    parse_html_sax(src, &mut handler);
+    handler.flush_text();
+
+    assert_eq!(
+        handler.events,
+        vec![
+            "doctype Some(\"html\")",
+            "start title 15..22",
+            "text \"a<b\"",
+            "end title",
+            "comment \"c\"",
+            "start script 41..49",
+            "text \"if (a<b) {}\"",
+            "end script",
+            "start p 69..82",
+            "text \"e&f\"",
+            "end p",
+        ]
+    );
+}
+
+#[test]
+fn strict_entities() {
+    let src = r#"<a href="?a=b&copy=c">&copy</a>"#;
+
+    let count_missing_semicolon = |options: LexerOptions| {
+        let mut lexer = Lexer::with_options(
+            StringInput::new(src, BytePos(0), BytePos(src.len() as u32)),
+            options,
+        );
+
+        for _ in lexer.by_ref() {}
+
+        lexer
+            .take_errors()
+            .iter()
+            .filter(|error| {
+                matches!(
+                    error.kind(),
+                    ErrorKind::MissingSemicolonAfterCharacterReference
+                )
+            })
+            .count()
+    };
+
+    assert_eq!(count_missing_semicolon(Default::default()), 1);
+    assert_eq!(
+        count_missing_semicolon(LexerOptions {
+            strict_entities: true
+        }),
+        2
+    );
+}
+
+#[test]
+fn sniff_encoding_from_bytes() {
+    assert_eq!(sniff_encoding(b"\xEF\xBB\xBF<p>a</p>").name(), "UTF-8");
+    assert_eq!(
+        sniff_encoding(b"\xFF\xFE<\x00p\x00>\x00").name(),
+        "UTF-16LE"
+    );
+    assert_eq!(
+        sniff_encoding(b"<!doctype html><meta charset=\"windows-1251\">").name(),
+        "windows-1251"
+    );
+    assert_eq!(
+        sniff_encoding(b"<META CHARSET=latin1>").name(),
+        "windows-1252"
+    );
+    assert_eq!(
+        sniff_encoding(
+            b"<meta http-equiv=\"Content-Type\" content=\"text/html; charset='koi8-r'\">"
+        )
+        .name(),
+        "KOI8-R"
+    );
+    // `content` is ignored without `http-equiv`
+    assert_eq!(
+        sniff_encoding(b"<meta content=\"text/html; charset=koi8-r\">").name(),
+        "UTF-8"
+    );
+    // `<meta>` in comments and attribute values is ignored
+    assert_eq!(
+        sniff_encoding(b"<!-- <meta charset=koi8-r> --><div title=\"<meta charset=koi8-r>\">")
+            .name(),
+        "UTF-8"
+    );
+    // UTF-16 declared in `<meta>` can't be right, because the prescan reads ASCII
+    assert_eq!(sniff_encoding(b"<meta charset=utf-16le>").name(), "UTF-8");
+    assert_eq!(sniff_encoding(b"<p>a</p>").name(), "UTF-8");
+}
+
+#[test]
+fn parse_document_from_bytes() {
+    let bytes = b"<!doctype html><meta charset=\"windows-1252\"><title>caf\xE9</title>";
+    let mut errors = vec![];
+    let (document, encoding) =
+        parse_document_bytes(bytes, Default::default(), &mut errors).unwrap();
+
+    assert_eq!(encoding.name(), "windows-1252");
+    assert!(errors.is_empty());
+
+    let title = document.children[1].find_first(
+        |node| matches!(node, Child::Element(element) if &*element.tag_name == "title"),
+    );
+
+    assert!(matches!(
+        title,
+        Some(Child::Element(element)) if matches!(
+            &*element.children,
+            [Child::Text(text)] if &*text.data == "caf\u{e9}"
+        )
+    ));
+}
+
+#[test]
+fn document_base_uri() {
+    let base_uri = |source: &str| {
+        let mut errors = vec![];
+        let (document, _) =
+            parse_document_bytes(source.as_bytes(), Default::default(), &mut errors).unwrap();
+
+        document.base_uri().map(|base_uri| base_uri.to_string())
+    };
+
+    assert_eq!(base_uri("<!doctype html><title>a</title>"), None);
+    assert_eq!(
+        base_uri("<!doctype html><base target=\"_blank\"><base href=\"/a/\"><base href=\"/b/\">"),
+        Some("/a/".to_string())
+    );
+    assert_eq!(
+        base_uri("<!doctype html><base href=\"\">"),
+        Some("".to_string())
+    );
+    assert_eq!(
+        base_uri("<!doctype html><base href><base href=\"/a/\">"),
+        Some("".to_string())
+    );
+}
@@ -0,0 +1,971 @@
+// Foster parenting moves content which is misplaced in `<table>` before the
+// table. Expected trees are in the html5lib tree construction format and match
+// the output of the html5lib python parser
+use crate::common::document_dom_tree_test as test;
+
+mod common;
+
+#[test]
+fn text_before_tbody() {
+    test(
+        "<!DOCTYPE html><table>x<tbody></tbody></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     "x"
+|     <table>
+|       <tbody>
+"#,
+    );
+}
+
+#[test]
+fn text_in_tbody() {
+    test(
+        "<!DOCTYPE html><table><tbody>x</tbody></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     "x"
+|     <table>
+|       <tbody>
+"#,
+    );
+}
+
+#[test]
+fn text_in_thead() {
+    test(
+        "<!DOCTYPE html><table><thead>x</thead></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     "x"
+|     <table>
+|       <thead>
+"#,
+    );
+}
+
+#[test]
+fn text_in_tfoot() {
+    test(
+        "<!DOCTYPE html><table><tfoot>x</tfoot></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     "x"
+|     <table>
+|       <tfoot>
+"#,
+    );
+}
+
+#[test]
+fn text_after_cell_in_row() {
+    test(
+        "<!DOCTYPE html><table><tr><td>a</td>b</tr></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     "b"
+|     <table>
+|       <tbody>
+|         <tr>
+|           <td>
+|             "a"
+"#,
+    );
+}
+
+#[test]
+fn text_with_surrounding_whitespace() {
+    test(
+        "<!DOCTYPE html><table> a </table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     " a "
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn whitespace_in_row_is_not_foster_parented() {
+    test(
+        "<!DOCTYPE html><table><tr> </tr></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <tbody>
+|         <tr>
+|           " "
+"#,
+    );
+}
+
+#[test]
+fn text_and_whitespace_in_row() {
+    test(
+        "<!DOCTYPE html><table><tr>  x  </tr></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     "  x  "
+|     <table>
+|       <tbody>
+|         <tr>
+"#,
+    );
+}
+
+#[test]
+fn text_split_by_row_is_merged() {
+    test(
+        "<!DOCTYPE html><table>a<tr></tr>b</table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     "ab"
+|     <table>
+|       <tbody>
+|         <tr>
+"#,
+    );
+}
+
+#[test]
+fn text_split_by_multiple_rows_is_merged() {
+    test(
+        "<!DOCTYPE html><table>a<tr></tr>b<tr></tr>c</table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     "abc"
+|     <table>
+|       <tbody>
+|         <tr>
+|         <tr>
+"#,
+    );
+}
+
+#[test]
+fn text_before_caption() {
+    test(
+        "<!DOCTYPE html><table>a<caption>b</caption></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     "a"
+|     <table>
+|       <caption>
+|         "b"
+"#,
+    );
+}
+
+#[test]
+fn text_after_caption() {
+    test(
+        "<!DOCTYPE html><table><caption>a</caption>b</table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     "b"
+|     <table>
+|       <caption>
+|         "a"
+"#,
+    );
+}
+
+#[test]
+fn text_after_col() {
+    test(
+        "<!DOCTYPE html><table><colgroup><col>x</table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     "x"
+|     <table>
+|       <colgroup>
+|         <col>
+"#,
+    );
+}
+
+#[test]
+fn comment_is_not_foster_parented() {
+    test(
+        "<!DOCTYPE html><table><!--x--></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <!-- x -->
+"#,
+    );
+}
+
+#[test]
+fn comment_flushes_pending_text() {
+    test(
+        "<!DOCTYPE html><table>a<!--c--></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     "a"
+|     <table>
+|       <!-- c -->
+"#,
+    );
+}
+
+#[test]
+fn whitespace_before_element_stays_in_table() {
+    test(
+        "<!DOCTYPE html><table> <div></div></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <div>
+|     <table>
+|       " "
+"#,
+    );
+}
+
+#[test]
+fn whitespace_in_foster_parented_element() {
+    test(
+        "<!DOCTYPE html><table><div> </div></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <div>
+|       " "
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn text_between_tables() {
+    test(
+        "<!DOCTYPE html><table><tr><td>a</td></tr></table><table>b</table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <tbody>
+|         <tr>
+|           <td>
+|             "a"
+|     "b"
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn table_start_tag_flushes_pending_text() {
+    test(
+        "<!DOCTYPE html><table>a<table>b</table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     "a"
+|     <table>
+|     "b"
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn span() {
+    test(
+        "<!DOCTYPE html><table><span>x</span></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <span>
+|       "x"
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn span_closed_by_row() {
+    test(
+        "<!DOCTYPE html><table><span>a<tr><td>b</td></tr></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <span>
+|       "a"
+|     <table>
+|       <tbody>
+|         <tr>
+|           <td>
+|             "b"
+"#,
+    );
+}
+
+#[test]
+fn span_in_row() {
+    test(
+        "<!DOCTYPE html><table><tr><td>a</td><span>b</span></tr></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <span>
+|       "b"
+|     <table>
+|       <tbody>
+|         <tr>
+|           <td>
+|             "a"
+"#,
+    );
+}
+
+#[test]
+fn div_between_rows() {
+    test(
+        "<!DOCTYPE html><table><tr><td></td></tr><div>a</div><tr><td>b</td></tr></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <div>
+|       "a"
+|     <table>
+|       <tbody>
+|         <tr>
+|           <td>
+|         <tr>
+|           <td>
+|             "b"
+"#,
+    );
+}
+
+#[test]
+fn anchor() {
+    test(
+        "<!DOCTYPE html><table><a>x</a></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <a>
+|       "x"
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn anchor_with_attribute() {
+    test(
+        "<!DOCTYPE html><table><a href=\"x\">y</table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <a>
+|       href="x"
+|       "y"
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn anchor_is_reconstructed_after_table() {
+    test(
+        "<!DOCTYPE html><table><a>1<tr><td>2</td></tr></table>3",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <a>
+|       "1"
+|     <table>
+|       <tbody>
+|         <tr>
+|           <td>
+|             "2"
+|     <a>
+|       "3"
+"#,
+    );
+}
+
+#[test]
+fn bold_then_text() {
+    test(
+        "<!DOCTYPE html><table><b>a</b>b</table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <b>
+|       "a"
+|     "b"
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn bold_is_reconstructed_for_foster_parented_text() {
+    test(
+        "<!DOCTYPE html><table><b><tr><td>aaa</td></tr>bbb</table>ccc",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <b>
+|     <b>
+|       "bbb"
+|     <table>
+|       <tbody>
+|         <tr>
+|           <td>
+|             "aaa"
+|     <b>
+|       "ccc"
+"#,
+    );
+}
+
+#[test]
+fn paragraph() {
+    test(
+        "<!DOCTYPE html><table><p>x</p></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <p>
+|       "x"
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn paragraph_closed_by_row() {
+    test(
+        "<!DOCTYPE html><table><p>x<tr><td>y</td></tr></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <p>
+|       "x"
+|     <table>
+|       <tbody>
+|         <tr>
+|           <td>
+|             "y"
+"#,
+    );
+}
+
+#[test]
+fn stray_paragraph_end_tag() {
+    test(
+        "<!DOCTYPE html><table></p></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <p>
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn stray_br_end_tag() {
+    test(
+        "<!DOCTYPE html><table></br></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <br>
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn list() {
+    test(
+        "<!DOCTYPE html><table><ul><li>a</li></ul></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <ul>
+|       <li>
+|         "a"
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn heading() {
+    test(
+        "<!DOCTYPE html><table><h1>x</h1></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <h1>
+|       "x"
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn pre_ignores_leading_newline() {
+    test(
+        "<!DOCTYPE html><table><pre>\nx</pre></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <pre>
+|       "x"
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn button() {
+    test(
+        "<!DOCTYPE html><table><button>a</button></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <button>
+|       "a"
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn option() {
+    test(
+        "<!DOCTYPE html><table><option>a</table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <option>
+|       "a"
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn textarea() {
+    test(
+        "<!DOCTYPE html><table><textarea>x</textarea></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <textarea>
+|       "x"
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn iframe() {
+    test(
+        "<!DOCTYPE html><table><iframe>x</iframe></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <iframe>
+|       "x"
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn img() {
+    test(
+        "<!DOCTYPE html><table><img></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <img>
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn br() {
+    test(
+        "<!DOCTYPE html><table><br></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <br>
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn hr() {
+    test(
+        "<!DOCTYPE html><table><hr></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <hr>
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn meta() {
+    test(
+        "<!DOCTYPE html><table><meta></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <meta>
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn input_without_type() {
+    test(
+        "<!DOCTYPE html><table><input></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <input>
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn hidden_input_is_case_insensitive() {
+    test(
+        "<!DOCTYPE html><table><input type=\"HIDDEN\"></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <input>
+|         type="HIDDEN"
+"#,
+    );
+}
+
+#[test]
+fn svg() {
+    test(
+        "<!DOCTYPE html><table><svg><circle/></svg></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <svg svg>
+|       <svg circle>
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn math() {
+    test(
+        "<!DOCTYPE html><table><math><mi>x</mi></math></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <math math>
+|       <math mi>
+|         "x"
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn nested_table_text() {
+    test(
+        "<!DOCTYPE html><table><tr><td><table>x</table></td></tr></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <tbody>
+|         <tr>
+|           <td>
+|             "x"
+|             <table>
+"#,
+    );
+}
+
+#[test]
+fn nested_table_div() {
+    test(
+        "<!DOCTYPE html><table><tr><td><table><div>x</div></table></td></tr></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <tbody>
+|         <tr>
+|           <td>
+|             <div>
+|               "x"
+|             <table>
+"#,
+    );
+}
+
+#[test]
+fn nested_table_text_after_inner_table() {
+    test(
+        "<!DOCTYPE html><table><tr><td><table></table></td></tr>y</table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     "y"
+|     <table>
+|       <tbody>
+|         <tr>
+|           <td>
+|             <table>
+"#,
+    );
+}
+
+#[test]
+fn nested_table_text_is_merged_with_cell_text() {
+    test(
+        "<!DOCTYPE html><table><tr><td>a<table>b<tr><td>c</table></td></tr></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <tbody>
+|         <tr>
+|           <td>
+|             "ab"
+|             <table>
+|               <tbody>
+|                 <tr>
+|                   <td>
+|                     "c"
+"#,
+    );
+}
+
+#[test]
+fn nested_table_in_caption() {
+    test(
+        "<!DOCTYPE html><table><caption><table>x</table></caption></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <caption>
+|         "x"
+|         <table>
+"#,
+    );
+}
+
+#[test]
+fn table_in_div() {
+    test(
+        "<!DOCTYPE html><div><table>x</table></div>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <div>
+|       "x"
+|       <table>
+"#,
+    );
+}
+
+#[test]
+fn form_is_not_foster_parented() {
+    test(
+        "<!DOCTYPE html><table><form><tr><td>x</td></tr></form></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <form>
+|       <tbody>
+|         <tr>
+|           <td>
+|             "x"
+"#,
+    );
+}
+
+#[test]
+fn form_with_hidden_input() {
+    test(
+        "<!DOCTYPE html><table><form><input type=\"hidden\"></form></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <form>
+|       <input>
+|         type="hidden"
+"#,
+    );
+}
+
+#[test]
+fn form_with_text_input() {
+    test(
+        "<!DOCTYPE html><table><form><input type=\"text\"></form></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <input>
+|       type="text"
+|     <table>
+|       <form>
+"#,
+    );
+}
+
+#[test]
+fn second_form_is_ignored() {
+    test(
+        "<!DOCTYPE html><table><form><form></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <form>
+"#,
+    );
+}
+
+#[test]
+fn form_in_table_in_form_is_ignored() {
+    test(
+        "<!DOCTYPE html><form><table><form></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <form>
+|       <table>
+"#,
+    );
+}
+
+#[test]
+fn template_content_is_not_foster_parented() {
+    test(
+        "<!DOCTYPE html><table><template>x</template></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <template>
+|         content
+|           "x"
+"#,
+    );
+}
+
+#[test]
+fn text_in_table_in_template() {
+    test(
+        "<!DOCTYPE html><template><table>x</table></template>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|     <template>
+|       content
+|         "x"
+|         <table>
+|   <body>
+"#,
+    );
+}
@@ -0,0 +1,626 @@
+// Expected trees are in the html5lib tree construction format and match the
+// output of the html5lib python parser
+use crate::common::document_dom_tree_test as test;
+
+mod common;
+
+#[test]
+fn table_with_cell() {
+    test(
+        "<!DOCTYPE html><table><tr><td>a</td></tr></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <tbody>
+|         <tr>
+|           <td>
+|             "a"
+"#,
+    );
+}
+
+#[test]
+fn text_is_foster_parented() {
+    test(
+        "<!DOCTYPE html><table>a<tr><td>b</td></tr></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     "a"
+|     <table>
+|       <tbody>
+|         <tr>
+|           <td>
+|             "b"
+"#,
+    );
+}
+
+#[test]
+fn multiple_characters_are_foster_parented_together() {
+    test(
+        "<!DOCTYPE html><table>a b<tr></tr></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     "a b"
+|     <table>
+|       <tbody>
+|         <tr>
+"#,
+    );
+}
+
+#[test]
+fn whitespace_is_kept_in_table() {
+    test(
+        "<!DOCTYPE html><table> <tr><td>b</td></tr></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       " "
+|       <tbody>
+|         <tr>
+|           <td>
+|             "b"
+"#,
+    );
+}
+
+#[test]
+fn text_in_table_body_is_foster_parented() {
+    test(
+        "<!DOCTYPE html><table><tr><td>1</td></tr> x </table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     " x "
+|     <table>
+|       <tbody>
+|         <tr>
+|           <td>
+|             "1"
+"#,
+    );
+}
+
+#[test]
+fn text_in_row_is_foster_parented() {
+    test(
+        "<!DOCTYPE html><table><tr>a</tr></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     "a"
+|     <table>
+|       <tbody>
+|         <tr>
+"#,
+    );
+}
+
+#[test]
+fn caption() {
+    test(
+        "<!DOCTYPE html><table><caption>cap</caption><tr><td>x</td></tr></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <caption>
+|         "cap"
+|       <tbody>
+|         <tr>
+|           <td>
+|             "x"
+"#,
+    );
+}
+
+#[test]
+fn row_closes_caption() {
+    test(
+        "<!DOCTYPE html><table><caption>a<tr><td>b</table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <caption>
+|         "a"
+|       <tbody>
+|         <tr>
+|           <td>
+|             "b"
+"#,
+    );
+}
+
+#[test]
+fn table_in_caption() {
+    test(
+        "<!DOCTYPE html><table><caption><table></table></caption></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <caption>
+|         <table>
+"#,
+    );
+}
+
+#[test]
+fn stray_cell_end_tag_in_caption() {
+    test(
+        "<!DOCTYPE html><table><caption></td></caption></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <caption>
+"#,
+    );
+}
+
+#[test]
+fn colgroup() {
+    test(
+        "<!DOCTYPE html><table><colgroup><col></colgroup></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <colgroup>
+|         <col>
+"#,
+    );
+}
+
+#[test]
+fn col_implies_colgroup() {
+    test(
+        "<!DOCTYPE html><table><col></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <colgroup>
+|         <col>
+"#,
+    );
+}
+
+#[test]
+fn text_in_colgroup_is_foster_parented() {
+    test(
+        "<!DOCTYPE html><table><colgroup>x</colgroup></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     "x"
+|     <table>
+|       <colgroup>
+"#,
+    );
+}
+
+#[test]
+fn cell_implies_tbody_and_row() {
+    test(
+        "<!DOCTYPE html><table><td>a</td></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <tbody>
+|         <tr>
+|           <td>
+|             "a"
+"#,
+    );
+}
+
+#[test]
+fn header_cell_implies_tbody_and_row() {
+    test(
+        "<!DOCTYPE html><table><th>h</th></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <tbody>
+|         <tr>
+|           <th>
+|             "h"
+"#,
+    );
+}
+
+#[test]
+fn unclosed_cells() {
+    test(
+        "<!DOCTYPE html><table><tr><td>a<td>b</table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <tbody>
+|         <tr>
+|           <td>
+|             "a"
+|           <td>
+|             "b"
+"#,
+    );
+}
+
+#[test]
+fn multiple_rows() {
+    test(
+        "<!DOCTYPE html><table><tr><td>a</td><td>b</td></tr><tr><td>c</td></tr></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <tbody>
+|         <tr>
+|           <td>
+|             "a"
+|           <td>
+|             "b"
+|         <tr>
+|           <td>
+|             "c"
+"#,
+    );
+}
+
+#[test]
+fn nested_table() {
+    test(
+        "<!DOCTYPE html><table><tr><td><table><tr><td>inner</td></tr></table></td></tr></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <tbody>
+|         <tr>
+|           <td>
+|             <table>
+|               <tbody>
+|                 <tr>
+|                   <td>
+|                     "inner"
+"#,
+    );
+}
+
+#[test]
+fn table_in_table() {
+    test(
+        "<!DOCTYPE html><table><table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn stray_div_end_tag() {
+    test(
+        "<!DOCTYPE html><table></div></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn stray_table_section_end_tags() {
+    test(
+        "<!DOCTYPE html><table></tbody></tr></td></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn stray_cell_end_tag_in_row() {
+    test(
+        "<!DOCTYPE html><table><tr></td></tr></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <tbody>
+|         <tr>
+"#,
+    );
+}
+
+#[test]
+fn stray_table_end_tag() {
+    test(
+        "<!DOCTYPE html><table></table></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn tbody_end_tag_closes_row() {
+    test(
+        "<!DOCTYPE html><table><tbody><tr></tbody><tr></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <tbody>
+|         <tr>
+|       <tbody>
+|         <tr>
+"#,
+    );
+}
+
+#[test]
+fn thead_end_tag_closes_cell() {
+    test(
+        "<!DOCTYPE html><table><thead><tr><td>a</thead><tbody><tr><td>b</table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <thead>
+|         <tr>
+|           <td>
+|             "a"
+|       <tbody>
+|         <tr>
+|           <td>
+|             "b"
+"#,
+    );
+}
+
+#[test]
+fn tfoot_before_rows() {
+    test(
+        "<!DOCTYPE html><table><tfoot><tr><td>f</td></tr></tfoot><tr><td>b</td></tr></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <tfoot>
+|         <tr>
+|           <td>
+|             "f"
+|       <tbody>
+|         <tr>
+|           <td>
+|             "b"
+"#,
+    );
+}
+
+#[test]
+fn div_is_foster_parented() {
+    test(
+        "<!DOCTYPE html><table><div>x</div></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <div>
+|       "x"
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn formatting_element_is_foster_parented() {
+    test(
+        "<!DOCTYPE html><table><b>x</b></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <b>
+|       "x"
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn formatting_element_in_cell() {
+    test(
+        "<!DOCTYPE html><table><tr><td><b>bold</td></tr></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <tbody>
+|         <tr>
+|           <td>
+|             <b>
+|               "bold"
+"#,
+    );
+}
+
+#[test]
+fn hidden_input() {
+    test(
+        "<!DOCTYPE html><table><input type=\"hidden\"></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <input>
+|         type="hidden"
+"#,
+    );
+}
+
+#[test]
+fn text_input_is_foster_parented() {
+    test(
+        "<!DOCTYPE html><table><input type=\"text\"></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <input>
+|       type="text"
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn form() {
+    test(
+        "<!DOCTYPE html><table><form></form></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <form>
+"#,
+    );
+}
+
+#[test]
+fn style() {
+    test(
+        "<!DOCTYPE html><table><style>a{}</style></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <style>
+|         "a{}"
+"#,
+    );
+}
+
+#[test]
+fn script() {
+    test(
+        "<!DOCTYPE html><table><script>x</script></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <script>
+|         "x"
+"#,
+    );
+}
+
+#[test]
+fn select_is_foster_parented() {
+    test(
+        "<!DOCTYPE html><table><select><option>o</select></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <select>
+|       <option>
+|         "o"
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn text_after_table() {
+    test(
+        "<!DOCTYPE html><table><tr><td>a</td></tr></table>b",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <table>
+|       <tbody>
+|         <tr>
+|           <td>
+|             "a"
+|     "b"
+"#,
+    );
+}
+
+#[test]
+fn table_closes_paragraph() {
+    test(
+        "<!DOCTYPE html><p><table></table>",
+        r#"| <!DOCTYPE html>
+| <html>
+|   <head>
+|   <body>
+|     <p>
+|     <table>
+"#,
+    );
+}
+
+#[test]
+fn table_in_paragraph_in_quirks_mode() {
+    test(
+        "<p><table></table>",
+        r#"| <html>
+|   <head>
+|   <body>
+|     <p>
+|       <table>
+"#,
+    );
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "async")]
+#![deny(warnings)]
+
+use bytes::Bytes;
+use futures::{executor::block_on, stream, StreamExt};
+use swc_common::{input::StringInput, BytePos};
+use swc_html_parser::{lexer::Lexer, streaming::StreamingLexer};
+
+#[test]
+fn streaming_lexer_emits_the_same_tokens() {
+    let src = "<!doctype html><div class=\"a\">caf\u{e9} &amp; &copy<!-- comment --></div>";
+
+    let lexer = Lexer::new(StringInput::new(src, BytePos(0), BytePos(src.len() as u32)));
+    let expected: Vec<_> = lexer.collect();
+
+    // Chunks of one byte split the two bytes of `é`
+    for chunk_size in [1, 2, 3, 7, src.len()] {
+        let chunks: Vec<_> = src
+            .as_bytes()
+            .chunks(chunk_size)
+            .map(Bytes::copy_from_slice)
+            .collect();
+
+        let items: Vec<_> = block_on(StreamingLexer::new(stream::iter(chunks)).collect());
+        let tokens: Vec<_> = items.into_iter().filter_map(Result::ok).collect();
+
+        assert_eq!(tokens, expected, "chunk size: {}", chunk_size);
+    }
+}
+
+#[test]
+fn streaming_lexer_only_skips_leading_bom() {
+    let src = "\u{feff}<p>a</p>\u{feff}b<!-- a long comment -->";
+
+    let lexer = Lexer::new(StringInput::new(src, BytePos(0), BytePos(src.len() as u32)));
+    let expected: Vec<_> = lexer.collect();
+
+    // Tokenizing resumes right before the second `U+FEFF`
+    let chunks = src
+        .as_bytes()
+        .chunks(11)
+        .map(Bytes::copy_from_slice)
+        .collect::<Vec<_>>();
+
+    let items: Vec<_> = block_on(StreamingLexer::new(stream::iter(chunks)).collect());
+    let tokens: Vec<_> = items.into_iter().filter_map(Result::ok).collect();
+
+    assert_eq!(tokens, expected);
+}
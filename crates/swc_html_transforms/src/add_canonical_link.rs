@@ -0,0 +1,87 @@
+use swc_atoms::js_word;
+use swc_common::DUMMY_SP;
+use swc_html_ast::*;
+use swc_html_visit::{VisitMut, VisitMutWith};
+
+use crate::util::get_head_mut;
+
+/// Inserts `<link rel="canonical" href="...">` at the end of `<head>`.
+///
+/// Nothing is inserted when the document already has a canonical link, its
+/// `href` is replaced with `canonical_url` when `replace_existing` is `true`.
+#[derive(Debug, Clone, Default)]
+pub struct AddCanonicalLink {
+    pub canonical_url: String,
+    pub replace_existing: bool,
+}
+
+impl AddCanonicalLink {
+    fn create_canonical_link(&self) -> Child {
+        let mut link = Element {
+            span: DUMMY_SP,
+            tag_name: js_word!("link"),
+            namespace: Namespace::HTML,
+            attributes: vec![],
+            children: vec![],
+            content: None,
+            is_self_closing: false,
+        };
+
+        link.set_attribute("rel", Some("canonical"));
+        link.set_attribute("href", Some(&self.canonical_url));
+
+        Child::Element(link)
+    }
+}
+
+impl VisitMut for AddCanonicalLink {
+    fn visit_mut_document(&mut self, n: &mut Document) {
+        let mut updater = CanonicalLinkUpdater {
+            canonical_url: self.replace_existing.then_some(&*self.canonical_url),
+            found: false,
+        };
+
+        n.visit_mut_children_with(&mut updater);
+
+        if updater.found {
+            return;
+        }
+
+        let head = get_head_mut(n);
+
+        if let Some(head) = head {
+            head.children.push(self.create_canonical_link());
+        }
+    }
+}
+
+struct CanonicalLinkUpdater<'a> {
+    canonical_url: Option<&'a str>,
+    found: bool,
+}
+
+impl VisitMut for CanonicalLinkUpdater<'_> {
+    fn visit_mut_element(&mut self, n: &mut Element) {
+        n.visit_mut_children_with(self);
+
+        if n.namespace != Namespace::HTML || n.tag_name != js_word!("link") || !is_canonical_link(n)
+        {
+            return;
+        }
+
+        self.found = true;
+
+        let canonical_url = match self.canonical_url {
+            Some(canonical_url) => canonical_url,
+            None => return,
+        };
+
+        n.set_attribute("href", Some(canonical_url));
+    }
+}
+
+fn is_canonical_link(n: &Element) -> bool {
+    matches!(n.get_attribute("rel"), Some(rel) if rel
+        .split_ascii_whitespace()
+        .any(|rel| rel.eq_ignore_ascii_case("canonical")))
+}
@@ -0,0 +1,55 @@
+use swc_atoms::js_word;
+use swc_html_ast::*;
+use swc_html_visit::{VisitMut, VisitMutWith};
+
+/// Adds the `integrity` attribute for Subresource Integrity to
+/// `<script src="...">` and `<link rel="stylesheet | preload | modulepreload"
+/// href="...">` elements.
+///
+/// `compute_hash` is called with the value of `src` or `href` and should
+/// return the value of the attribute, i.e. `sha384-...`. The
+/// `crossorigin="anonymous"` attribute is added too, unless the element already
+/// has the `crossorigin` attribute.
+///
+/// Elements which already have the `integrity` attribute are left untouched.
+pub struct AddIntegrity {
+    pub compute_hash: Box<dyn Fn(&str) -> String>,
+}
+
+impl VisitMut for AddIntegrity {
+    fn visit_mut_element(&mut self, n: &mut Element) {
+        n.visit_mut_children_with(self);
+
+        if n.namespace != Namespace::HTML || n.has_attribute("integrity") {
+            return;
+        }
+
+        let url = match n.tag_name {
+            js_word!("script") => n.get_attribute("src"),
+            js_word!("link") if is_subresource_link(n) => n.get_attribute("href"),
+            _ => None,
+        };
+
+        let hash = match url {
+            Some(url) if !url.is_empty() => (self.compute_hash)(url),
+            _ => return,
+        };
+
+        n.set_attribute("integrity", Some(&hash));
+
+        if !n.has_attribute("crossorigin") {
+            n.set_attribute("crossorigin", Some("anonymous"));
+        }
+    }
+}
+
+fn is_subresource_link(n: &Element) -> bool {
+    match n.get_attribute("rel") {
+        Some(rel) => rel.split_ascii_whitespace().any(|rel| {
+            rel.eq_ignore_ascii_case("stylesheet")
+                || rel.eq_ignore_ascii_case("preload")
+                || rel.eq_ignore_ascii_case("modulepreload")
+        }),
+        None => false,
+    }
+}
@@ -0,0 +1,49 @@
+use swc_atoms::js_word;
+use swc_html_ast::*;
+use swc_html_visit::{VisitMut, VisitMutWith};
+
+/// Adds the `nonce` attribute for Content Security Policy to `<script>` and
+/// `<style>` elements.
+///
+/// Elements which already have the `nonce` attribute are left untouched.
+/// External scripts (`<script src="...">`) and stylesheets
+/// (`<link rel="stylesheet" href="...">`) are ignored unless
+/// `include_external` is `true`.
+#[derive(Debug, Clone, Default)]
+pub struct AddNonce {
+    pub nonce: String,
+    pub include_external: bool,
+}
+
+impl AddNonce {
+    fn is_applicable(&self, n: &Element) -> bool {
+        if n.namespace != Namespace::HTML {
+            return false;
+        }
+
+        match n.tag_name {
+            js_word!("style") => true,
+            js_word!("script") => self.include_external || !n.has_attribute("src"),
+            js_word!("link") => {
+                self.include_external
+                    && n.has_attribute("href")
+                    && matches!(n.get_attribute("rel"), Some(rel) if rel
+                        .split_ascii_whitespace()
+                        .any(|rel| rel.eq_ignore_ascii_case("stylesheet")))
+            }
+            _ => false,
+        }
+    }
+}
+
+impl VisitMut for AddNonce {
+    fn visit_mut_element(&mut self, n: &mut Element) {
+        n.visit_mut_children_with(self);
+
+        if !self.is_applicable(n) || n.has_attribute("nonce") {
+            return;
+        }
+
+        n.set_attribute("nonce", Some(&self.nonce));
+    }
+}
@@ -0,0 +1,181 @@
+use swc_html_ast::*;
+use swc_html_visit::{VisitMut, VisitMutWith};
+use url::Url;
+
+/// Rewrites relative URLs in `src`, `href`, `action` and `srcset` attributes
+/// to absolute ones using the URL from the first `<base href="...">` element.
+///
+/// Documents without `<base>` or with a relative `href` in `<base>` are left
+/// untouched, because we don't know the URL of the document itself.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BaseUrlResolver;
+
+impl VisitMut for BaseUrlResolver {
+    fn visit_mut_document(&mut self, n: &mut Document) {
+        let mut finder = BaseUrlFinder::default();
+
+        n.visit_mut_with(&mut finder);
+
+        if let Some(base_url) = finder.base_url {
+            n.visit_mut_children_with(&mut UrlResolver { base_url });
+        }
+    }
+}
+
+struct UrlResolver {
+    base_url: Url,
+}
+
+impl UrlResolver {
+    fn resolve(&self, value: &str) -> Option<String> {
+        let value = value.trim();
+
+        // Leave empty values and absolute URLs (including `data:`, `mailto:`,
+        // `javascript:` and etc) as is
+        if value.is_empty() || Url::parse(value).is_ok() {
+            return None;
+        }
+
+        self.base_url.join(value).ok().map(String::from)
+    }
+
+    fn resolve_srcset(&self, value: &str) -> Option<String> {
+        map_srcset(value, |url| self.resolve(url))
+    }
+}
+
+impl VisitMut for UrlResolver {
+    fn visit_mut_element(&mut self, n: &mut Element) {
+        n.visit_mut_children_with(self);
+
+        // The `href` attribute of `<base>` is the base URL itself
+        if n.namespace != Namespace::HTML || &*n.tag_name == "base" {
+            return;
+        }
+
+        for attribute in n.attributes.iter_mut() {
+            if attribute.namespace.is_some() {
+                continue;
+            }
+
+            let value = match &attribute.value {
+                Some(value) => value,
+                _ => continue,
+            };
+
+            let resolved = match &*attribute.name {
+                "src" | "href" | "action" => self.resolve(value),
+                "srcset" => self.resolve_srcset(value),
+                _ => continue,
+            };
+
+            if let Some(resolved) = resolved {
+                attribute.value = Some(resolved.into());
+                attribute.raw_value = None;
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct BaseUrlFinder {
+    base_url: Option<Url>,
+    found: bool,
+}
+
+impl VisitMut for BaseUrlFinder {
+    fn visit_mut_element(&mut self, n: &mut Element) {
+        if self.found {
+            return;
+        }
+
+        // Only the first `<base>` element with the `href` attribute is used
+        if n.namespace == Namespace::HTML && &*n.tag_name == "base" {
+            if let Some(href) = n.get_attribute("href") {
+                self.found = true;
+                self.base_url = Url::parse(href.trim()).ok();
+
+                return;
+            }
+        }
+
+        n.visit_mut_children_with(self);
+    }
+}
+
+/// Calls `f` with every URL in `srcset`, returns the new `srcset` when `f`
+/// returns `Some` for at least one of them.
+///
+/// <https://html.spec.whatwg.org/multipage/images.html#parsing-a-srcset-attribute>
+pub(crate) fn map_srcset<F>(value: &str, f: F) -> Option<String>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let mut candidates = vec![];
+    let mut changed = false;
+    let mut rest = value;
+
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == ',');
+
+        if rest.is_empty() {
+            break;
+        }
+
+        let url_end = rest
+            .find(|c: char| c.is_ascii_whitespace())
+            .unwrap_or(rest.len());
+        let mut url = &rest[..url_end];
+
+        rest = &rest[url_end..];
+
+        let mut descriptors = "";
+
+        // If the URL ends with a comma, there are no descriptors
+        if url.ends_with(',') {
+            url = url.trim_end_matches(',');
+        } else {
+            let mut in_parens = false;
+            let descriptors_end = rest
+                .find(|c: char| match c {
+                    '(' => {
+                        in_parens = true;
+
+                        false
+                    }
+                    ')' => {
+                        in_parens = false;
+
+                        false
+                    }
+                    ',' => !in_parens,
+                    _ => false,
+                })
+                .unwrap_or(rest.len());
+
+            descriptors = rest[..descriptors_end].trim();
+            rest = &rest[descriptors_end..];
+        }
+
+        let url = match f(url) {
+            Some(resolved) => {
+                changed = true;
+
+                resolved
+            }
+            None => url.to_string(),
+        };
+
+        if descriptors.is_empty() {
+            candidates.push(url);
+        } else {
+            candidates.push(format!("{} {}", url, descriptors));
+        }
+    }
+
+    if !changed {
+        return None;
+    }
+
+    Some(candidates.join(", "))
+}
@@ -0,0 +1,40 @@
+use swc_html_ast::*;
+use swc_html_visit::{VisitMut, VisitMutWith};
+
+/// Transforms or removes attributes of elements, i.e. framework specific
+/// attributes like `*ngFor` or `v-if`.
+///
+/// `map` is called with the name and the value of every attribute, returning
+/// `None` removes the attribute, returning `Some((name, value))` replaces it.
+///
+/// Attribute names of HTML elements are lowercased by the parser, so `*ngFor`
+/// is passed as `*ngfor`.
+pub struct DataAttributeMapper {
+    #[allow(clippy::type_complexity)]
+    pub map: Box<dyn Fn(&str, Option<&str>) -> Option<(String, Option<String>)>>,
+}
+
+impl VisitMut for DataAttributeMapper {
+    fn visit_mut_element(&mut self, n: &mut Element) {
+        n.visit_mut_children_with(self);
+
+        n.attributes.retain_mut(|attribute| {
+            let (name, value) = match (self.map)(&attribute.name, attribute.value.as_deref()) {
+                Some(mapped) => mapped,
+                None => return false,
+            };
+
+            if *attribute.name != *name {
+                attribute.name = name.into();
+                attribute.raw_name = None;
+            }
+
+            if attribute.value.as_deref() != value.as_deref() {
+                attribute.value = value.map(Into::into);
+                attribute.raw_value = None;
+            }
+
+            true
+        });
+    }
+}
@@ -0,0 +1,69 @@
+use swc_atoms::{js_word, JsWord};
+use swc_common::Span;
+use swc_html_ast::*;
+use swc_html_visit::{VisitMut, VisitMutWith};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PictureSource {
+    /// The value of the `srcset` attribute
+    pub srcset: Option<JsWord>,
+    /// The value of the `media` attribute
+    pub media: Option<JsWord>,
+    /// The value of the `type` attribute
+    pub ty: Option<JsWord>,
+    /// The value of the `sizes` attribute
+    pub sizes: Option<JsWord>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtractedPicture {
+    /// The span of the `<picture>` element
+    pub span: Span,
+    /// `<source>` children in the document order
+    pub sources: Vec<PictureSource>,
+}
+
+/// Collects `<picture>` elements and their `<source>` children from the
+/// document in the document order.
+///
+/// The document itself is left untouched.
+#[derive(Debug, Default)]
+pub struct ExtractPictureSources {
+    pub pictures: Vec<ExtractedPicture>,
+}
+
+impl VisitMut for ExtractPictureSources {
+    fn visit_mut_element(&mut self, n: &mut Element) {
+        if n.namespace != Namespace::HTML || n.tag_name != js_word!("picture") {
+            n.visit_mut_children_with(self);
+
+            return;
+        }
+
+        let sources = n
+            .children
+            .iter()
+            .filter_map(|child| match child {
+                Child::Element(element)
+                    if element.namespace == Namespace::HTML
+                        && element.tag_name == js_word!("source") =>
+                {
+                    Some(PictureSource {
+                        srcset: element.get_attribute("srcset").map(JsWord::from),
+                        media: element.get_attribute("media").map(JsWord::from),
+                        ty: element.get_attribute("type").map(JsWord::from),
+                        sizes: element.get_attribute("sizes").map(JsWord::from),
+                    })
+                }
+                _ => None,
+            })
+            .collect();
+
+        self.pictures.push(ExtractedPicture {
+            span: n.span,
+            sources,
+        });
+
+        n.visit_mut_children_with(self);
+    }
+}
@@ -0,0 +1,59 @@
+use swc_atoms::{js_word, JsWord};
+use swc_common::BytePos;
+use swc_html_ast::*;
+use swc_html_visit::{VisitMut, VisitMutWith};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtractedScript {
+    /// The text content of the element, empty for external scripts without
+    /// content
+    pub text: String,
+    /// The value of the `src` attribute
+    pub src: Option<JsWord>,
+    /// The value of the `type` attribute
+    pub ty: Option<JsWord>,
+    /// The start position of the text child, used to map the extracted code
+    /// back to the original document
+    pub text_pos: Option<BytePos>,
+}
+
+/// Collects `<script>` elements from the document in the document order.
+///
+/// The document itself is left untouched.
+#[derive(Debug, Default)]
+pub struct ExtractScripts {
+    pub scripts: Vec<ExtractedScript>,
+}
+
+impl VisitMut for ExtractScripts {
+    fn visit_mut_element(&mut self, n: &mut Element) {
+        if n.namespace != Namespace::HTML || n.tag_name != js_word!("script") {
+            n.visit_mut_children_with(self);
+
+            return;
+        }
+
+        let src = n.get_attribute("src").map(JsWord::from);
+        let ty = n.get_attribute("type").map(JsWord::from);
+
+        let mut text = String::new();
+        let mut text_pos = None;
+
+        for child in n.children.iter() {
+            if let Child::Text(Text { span, data, .. }) = child {
+                if text_pos.is_none() {
+                    text_pos = Some(span.lo);
+                }
+
+                text.push_str(data);
+            }
+        }
+
+        self.scripts.push(ExtractedScript {
+            text,
+            src,
+            ty,
+            text_pos,
+        });
+    }
+}
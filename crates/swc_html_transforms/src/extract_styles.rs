@@ -0,0 +1,80 @@
+use swc_atoms::{js_word, JsWord};
+use swc_common::BytePos;
+use swc_html_ast::*;
+use swc_html_visit::{VisitMut, VisitMutWith};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtractedStyle {
+    /// The text content of the element
+    pub text: String,
+    /// The value of the `media` attribute
+    pub media: Option<JsWord>,
+    /// The start position of the text child, used to map the extracted code
+    /// back to the original document
+    pub text_pos: Option<BytePos>,
+}
+
+/// Collects `<style>` elements from the document in the document order.
+///
+/// If `href` is set, it is called for every extracted style and the element is
+/// replaced with `<link rel="stylesheet" href="...">` pointing to the returned
+/// URL, the `media` attribute is kept. Returning `None` leaves the element
+/// untouched.
+#[derive(Default)]
+pub struct ExtractStyles {
+    pub styles: Vec<ExtractedStyle>,
+    #[allow(clippy::type_complexity)]
+    pub href: Option<Box<dyn FnMut(&ExtractedStyle) -> Option<JsWord>>>,
+}
+
+impl VisitMut for ExtractStyles {
+    fn visit_mut_element(&mut self, n: &mut Element) {
+        if n.namespace != Namespace::HTML || n.tag_name != js_word!("style") {
+            n.visit_mut_children_with(self);
+
+            return;
+        }
+
+        let media = n.get_attribute("media").map(JsWord::from);
+
+        let mut text = String::new();
+        let mut text_pos = None;
+
+        for child in n.children.iter() {
+            if let Child::Text(Text { span, data, .. }) = child {
+                if text_pos.is_none() {
+                    text_pos = Some(span.lo);
+                }
+
+                text.push_str(data);
+            }
+        }
+
+        let style = ExtractedStyle {
+            text,
+            media,
+            text_pos,
+        };
+
+        if let Some(href) = self.href.as_mut().and_then(|href| href(&style)) {
+            *n = Element {
+                span: n.span,
+                tag_name: js_word!("link"),
+                namespace: Namespace::HTML,
+                attributes: vec![],
+                children: vec![],
+                content: None,
+                is_self_closing: false,
+            };
+
+            n.set_attribute("rel", Some("stylesheet"));
+            n.set_attribute("href", Some(&href));
+
+            if let Some(media) = &style.media {
+                n.set_attribute("media", Some(media));
+            }
+        }
+
+        self.styles.push(style);
+    }
+}
@@ -0,0 +1,132 @@
+use swc_html_ast::*;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Computes a hash of `node` and its descendants which can be used to detect
+/// whether a subtree has changed since it was serialized.
+///
+/// Tag names, namespaces, attributes (in order), text and comments are hashed,
+/// spans and raw values are ignored.
+///
+/// The hash is 64-bit FNV-1a over a fixed encoding of the tree, every string
+/// is written as its length (`u64`, little-endian) followed by its UTF-8
+/// bytes. It doesn't depend on the platform, the compiler or the version of a
+/// dependency, so it can be persisted as a cache key. It is not a
+/// cryptographic hash.
+pub fn hash_subtree(node: &Child) -> u64 {
+    let mut hasher = Fnv1a(FNV_OFFSET_BASIS);
+
+    hash_child(node, &mut hasher);
+
+    hasher.0
+}
+
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn write_u8(&mut self, value: u8) {
+        self.write_bytes(&[value]);
+    }
+
+    fn write_len(&mut self, len: usize) {
+        self.write_bytes(&(len as u64).to_le_bytes());
+    }
+
+    fn write_str(&mut self, value: &str) {
+        self.write_len(value.len());
+        self.write_bytes(value.as_bytes());
+    }
+
+    fn write_opt_str(&mut self, value: Option<&str>) {
+        match value {
+            Some(value) => {
+                self.write_u8(1);
+                self.write_str(value);
+            }
+            None => {
+                self.write_u8(0);
+            }
+        }
+    }
+
+    fn write_namespace(&mut self, namespace: Option<Namespace>) {
+        self.write_opt_str(namespace.map(|namespace| match namespace {
+            Namespace::HTML => "http://www.w3.org/1999/xhtml",
+            Namespace::MATHML => "http://www.w3.org/1998/Math/MathML",
+            Namespace::SVG => "http://www.w3.org/2000/svg",
+            Namespace::XLINK => "http://www.w3.org/1999/xlink",
+            Namespace::XML => "http://www.w3.org/XML/1998/namespace",
+            Namespace::XMLNS => "http://www.w3.org/2000/xmlns/",
+        }));
+    }
+}
+
+fn hash_child(node: &Child, hasher: &mut Fnv1a) {
+    match node {
+        Child::DocumentType(DocumentType {
+            name,
+            public_id,
+            system_id,
+            ..
+        }) => {
+            hasher.write_u8(0);
+            hasher.write_opt_str(name.as_deref());
+            hasher.write_opt_str(public_id.as_deref());
+            hasher.write_opt_str(system_id.as_deref());
+        }
+        Child::Element(element) => {
+            hasher.write_u8(1);
+            hash_element(element, hasher);
+        }
+        Child::Text(Text { data, .. }) => {
+            hasher.write_u8(2);
+            hasher.write_str(data);
+        }
+        Child::Comment(Comment { data, .. }) => {
+            hasher.write_u8(3);
+            hasher.write_str(data);
+        }
+    }
+}
+
+fn hash_element(element: &Element, hasher: &mut Fnv1a) {
+    hasher.write_str(&element.tag_name);
+    hasher.write_namespace(Some(element.namespace));
+
+    hasher.write_len(element.attributes.len());
+
+    for attribute in &element.attributes {
+        hasher.write_namespace(attribute.namespace);
+        hasher.write_opt_str(attribute.prefix.as_deref());
+        hasher.write_str(&attribute.name);
+        hasher.write_opt_str(attribute.value.as_deref());
+    }
+
+    hash_children(&element.children, hasher);
+
+    match &element.content {
+        Some(content) => {
+            hasher.write_u8(1);
+            hash_children(&content.children, hasher);
+        }
+        None => {
+            hasher.write_u8(0);
+        }
+    }
+}
+
+fn hash_children(children: &[Child], hasher: &mut Fnv1a) {
+    hasher.write_len(children.len());
+
+    for child in children {
+        hash_child(child, hasher);
+    }
+}
@@ -0,0 +1,68 @@
+use swc_atoms::{js_word, JsWord};
+use swc_html_ast::*;
+use swc_html_visit::{VisitMut, VisitMutWith};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InjectPosition {
+    /// After the last child of `<head>`
+    Append,
+    /// Before the first child of `<head>`
+    Prepend,
+}
+
+impl Default for InjectPosition {
+    fn default() -> Self {
+        InjectPosition::Append
+    }
+}
+
+/// Inserts `children` (i.e. `<link>` or `<style>` elements) into `<head>`.
+///
+/// Elements with the `id` attribute are skipped when `<head>` already has an
+/// element with the same `id`.
+#[derive(Debug, Clone, Default)]
+pub struct InjectHead {
+    pub children: Vec<Child>,
+    pub position: InjectPosition,
+}
+
+impl VisitMut for InjectHead {
+    fn visit_mut_element(&mut self, n: &mut Element) {
+        if n.namespace != Namespace::HTML || n.tag_name != js_word!("head") {
+            n.visit_mut_children_with(self);
+
+            return;
+        }
+
+        let mut ids: Vec<_> = n.children.iter().filter_map(get_id).collect();
+        let mut new_children = vec![];
+
+        for child in self.children.iter() {
+            if let Some(id) = get_id(child) {
+                if ids.contains(&id) {
+                    continue;
+                }
+
+                ids.push(id);
+            }
+
+            new_children.push(child.clone());
+        }
+
+        match self.position {
+            InjectPosition::Append => {
+                n.children.extend(new_children);
+            }
+            InjectPosition::Prepend => {
+                n.children.splice(0..0, new_children);
+            }
+        }
+    }
+}
+
+fn get_id(child: &Child) -> Option<JsWord> {
+    match child {
+        Child::Element(element) => element.get_attribute("id").map(JsWord::from),
+        _ => None,
+    }
+}
@@ -0,0 +1,121 @@
+use swc_atoms::js_word;
+use swc_common::DUMMY_SP;
+use swc_html_ast::*;
+use swc_html_visit::{Visit, VisitMut, VisitMutWith, VisitWith};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetaPosition {
+    /// The first child of `<head>`
+    First,
+    /// Right after `<title>`, or the first child of `<head>` when there is no
+    /// `<title>`
+    AfterTitle,
+}
+
+impl Default for MetaPosition {
+    fn default() -> Self {
+        MetaPosition::First
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct InjectMetaCharsetConfig {
+    pub charset: String,
+    pub position: MetaPosition,
+}
+
+impl Default for InjectMetaCharsetConfig {
+    fn default() -> Self {
+        InjectMetaCharsetConfig {
+            charset: "utf-8".into(),
+            position: Default::default(),
+        }
+    }
+}
+
+/// Inserts `<meta charset="...">` into `<head>`.
+///
+/// Does nothing when the document already has `<meta charset>` or
+/// `<meta http-equiv="content-type">`.
+#[derive(Debug, Clone, Default)]
+pub struct InjectMetaCharset {
+    pub config: InjectMetaCharsetConfig,
+}
+
+impl InjectMetaCharset {
+    fn create_meta_charset(&self) -> Child {
+        let mut meta = Element {
+            span: DUMMY_SP,
+            tag_name: js_word!("meta"),
+            namespace: Namespace::HTML,
+            attributes: vec![],
+            children: vec![],
+            content: None,
+            is_self_closing: false,
+        };
+
+        meta.set_attribute("charset", Some(&self.config.charset));
+
+        Child::Element(meta)
+    }
+}
+
+impl VisitMut for InjectMetaCharset {
+    fn visit_mut_document(&mut self, n: &mut Document) {
+        let mut finder = MetaCharsetFinder::default();
+
+        n.visit_with(&mut finder);
+
+        if finder.found {
+            return;
+        }
+
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_element(&mut self, n: &mut Element) {
+        if n.namespace != Namespace::HTML || n.tag_name != js_word!("head") {
+            n.visit_mut_children_with(self);
+
+            return;
+        }
+
+        let index = match self.config.position {
+            MetaPosition::First => 0,
+            MetaPosition::AfterTitle => n
+                .children
+                .iter()
+                .position(|child| {
+                    matches!(child, Child::Element(Element { namespace, tag_name, .. }) if *namespace == Namespace::HTML && *tag_name == js_word!("title"))
+                })
+                .map(|index| index + 1)
+                .unwrap_or(0),
+        };
+
+        n.children.insert(index, self.create_meta_charset());
+    }
+}
+
+#[derive(Debug, Default)]
+struct MetaCharsetFinder {
+    found: bool,
+}
+
+impl Visit for MetaCharsetFinder {
+    fn visit_element(&mut self, n: &Element) {
+        if self.found {
+            return;
+        }
+
+        if n.namespace == Namespace::HTML && n.tag_name == js_word!("meta") {
+            self.found = n.has_attribute("charset")
+                || matches!(n.get_attribute("http-equiv"), Some(value) if value
+                    .trim()
+                    .eq_ignore_ascii_case("content-type"));
+
+            return;
+        }
+
+        n.visit_children_with(self);
+    }
+}
@@ -0,0 +1,141 @@
+use std::mem::take;
+
+use swc_atoms::js_word;
+use swc_common::DUMMY_SP;
+use swc_html_ast::*;
+use swc_html_visit::{VisitMut, VisitMutWith};
+
+use crate::util::get_head_mut;
+
+/// Inlines `critical_css` into a `<style>` element inserted as the first child
+/// of `<head>` and defers loading of the stylesheets with `media="print"
+/// onload="this.media='all'"`.
+///
+/// The content of external stylesheets is not available here, so
+/// `should_defer` is called with the `href` of every `<link rel="stylesheet">`
+/// without a `media` attribute and should return `true` only for stylesheets
+/// covered by `critical_css`. Other links, including links with a `media`
+/// attribute, are left untouched.
+///
+/// Every deferred link is followed by `<noscript>` with the original link, so
+/// the stylesheet is loaded when scripting is disabled.
+pub struct InlineCriticalCss {
+    pub critical_css: String,
+    pub should_defer: Box<dyn Fn(&str) -> bool>,
+}
+
+impl InlineCriticalCss {
+    fn create_style(&self) -> Child {
+        Child::Element(Element {
+            span: DUMMY_SP,
+            tag_name: js_word!("style"),
+            namespace: Namespace::HTML,
+            attributes: vec![],
+            children: vec![Child::Text(Text {
+                span: DUMMY_SP,
+                data: self.critical_css.clone().into(),
+                raw: None,
+            })],
+            content: None,
+            is_self_closing: false,
+        })
+    }
+}
+
+impl VisitMut for InlineCriticalCss {
+    fn visit_mut_document(&mut self, n: &mut Document) {
+        if self.critical_css.trim().is_empty() {
+            return;
+        }
+
+        let head = match get_head_mut(n) {
+            Some(head) => head,
+            None => return,
+        };
+
+        head.children.insert(0, self.create_style());
+
+        n.visit_mut_children_with(&mut StylesheetDeferrer {
+            should_defer: &*self.should_defer,
+        });
+    }
+}
+
+struct StylesheetDeferrer<'a> {
+    should_defer: &'a dyn Fn(&str) -> bool,
+}
+
+impl StylesheetDeferrer<'_> {
+    fn is_deferrable(&self, n: &Element) -> bool {
+        if n.namespace != Namespace::HTML
+            || n.tag_name != js_word!("link")
+            || !is_stylesheet_link(n)
+            || n.has_attribute("media")
+        {
+            return false;
+        }
+
+        matches!(n.get_attribute("href"), Some(href) if (self.should_defer)(href))
+    }
+}
+
+impl VisitMut for StylesheetDeferrer<'_> {
+    fn visit_mut_element(&mut self, n: &mut Element) {
+        // Links in `<noscript>` are only used without scripting
+        if n.namespace == Namespace::HTML && n.tag_name == js_word!("noscript") {
+            return;
+        }
+
+        n.visit_mut_children_with(self);
+
+        if !n
+            .children
+            .iter()
+            .any(|child| matches!(child, Child::Element(element) if self.is_deferrable(element)))
+        {
+            return;
+        }
+
+        let mut children = Vec::with_capacity(n.children.len() + 1);
+
+        for child in take(&mut n.children) {
+            match child {
+                Child::Element(mut element) if self.is_deferrable(&element) => {
+                    let fallback = create_noscript(element.clone());
+
+                    element.set_attribute("media", Some("print"));
+                    element.set_attribute("onload", Some("this.media='all'"));
+
+                    children.push(Child::Element(element));
+                    children.push(fallback);
+                }
+                _ => {
+                    children.push(child);
+                }
+            }
+        }
+
+        n.children = children;
+    }
+}
+
+fn is_stylesheet_link(n: &Element) -> bool {
+    matches!(n.get_attribute("rel"), Some(rel) if rel
+        .split_ascii_whitespace()
+        .any(|rel| rel.eq_ignore_ascii_case("stylesheet")))
+}
+
+fn create_noscript(link: Element) -> Child {
+    Child::Element(Element {
+        span: DUMMY_SP,
+        tag_name: js_word!("noscript"),
+        namespace: Namespace::HTML,
+        attributes: vec![],
+        children: vec![Child::Element(Element {
+            span: DUMMY_SP,
+            ..link
+        })],
+        content: None,
+        is_self_closing: false,
+    })
+}
@@ -0,0 +1,134 @@
+use swc_atoms::js_word;
+use swc_common::{sync::Lrc, FileName, FilePathMapping, SourceMap, Span, DUMMY_SP};
+use swc_html_ast::*;
+use swc_html_parser::parse_file_as_document_fragment;
+use swc_html_visit::{VisitMut, VisitMutWith};
+
+/// Replaces `<img>` elements with `src` pointing to a `.svg` file with the
+/// inline `<svg>` element, so the SVG can be animated, scripted and styled
+/// from the document.
+///
+/// `resolver` is called with the value of `src` and should return the content
+/// of the SVG file, returning `None` or content without the `<svg>` element
+/// leaves the element untouched. The `id` and `class` attributes of `<img>`
+/// are kept, unless the `<svg>` element already has them.
+///
+/// The `alt` text is kept as `role="img"` with `aria-label`, an empty `alt`
+/// marks the `<svg>` element as decorative with `aria-hidden="true"`.
+///
+/// The SVG is parsed into a separate source map, so spans of the inlined
+/// elements are replaced with `DUMMY_SP` and the `<svg>` element gets the span
+/// of `<img>`.
+pub struct InlineSvg {
+    pub resolver: Box<dyn Fn(&str) -> Option<String>>,
+}
+
+impl VisitMut for InlineSvg {
+    fn visit_mut_element(&mut self, n: &mut Element) {
+        if n.namespace != Namespace::HTML || n.tag_name != js_word!("img") {
+            n.visit_mut_children_with(self);
+
+            return;
+        }
+
+        let src = match n.get_attribute("src") {
+            Some(src) if is_svg_url(src) => src,
+            _ => return,
+        };
+
+        let content = match (self.resolver)(src) {
+            Some(content) => content,
+            None => return,
+        };
+
+        let mut svg = match parse_svg(content) {
+            Some(svg) => svg,
+            None => return,
+        };
+
+        svg.visit_mut_with(&mut SpanRemover);
+
+        for name in ["id", "class"] {
+            if svg.has_attribute(name) {
+                continue;
+            }
+
+            if let Some(value) = n.get_attribute(name) {
+                svg.set_attribute(name, Some(value));
+            }
+        }
+
+        match n.get_attribute("alt") {
+            Some(_) if svg.has_attribute("role") || svg.has_attribute("aria-label") => {}
+            Some("") => {
+                if !svg.has_attribute("aria-hidden") {
+                    svg.set_attribute("aria-hidden", Some("true"));
+                }
+            }
+            Some(alt) => {
+                svg.set_attribute("role", Some("img"));
+                svg.set_attribute("aria-label", Some(alt));
+            }
+            None => {}
+        }
+
+        svg.span = n.span;
+
+        *n = svg;
+    }
+}
+
+struct SpanRemover;
+
+impl VisitMut for SpanRemover {
+    fn visit_mut_span(&mut self, n: &mut Span) {
+        *n = DUMMY_SP;
+    }
+}
+
+fn is_svg_url(url: &str) -> bool {
+    let path = match url.find(|c| c == '?' || c == '#') {
+        Some(pos) => &url[..pos],
+        None => url,
+    };
+
+    path.len() >= 4 && path[path.len() - 4..].eq_ignore_ascii_case(".svg")
+}
+
+fn parse_svg(content: String) -> Option<Element> {
+    let cm = Lrc::new(SourceMap::new(FilePathMapping::empty()));
+    let fm = cm.new_source_file(FileName::Anon, content);
+    let context_element = Element {
+        span: Default::default(),
+        tag_name: js_word!("body"),
+        namespace: Namespace::HTML,
+        attributes: vec![],
+        children: vec![],
+        content: None,
+        is_self_closing: false,
+    };
+
+    let mut errors = vec![];
+    let document_fragment = parse_file_as_document_fragment(
+        &fm,
+        &context_element,
+        DocumentMode::NoQuirks,
+        None,
+        Default::default(),
+        &mut errors,
+    )
+    .ok()?;
+
+    // The XML declaration and the doctype are parsed as comments
+    document_fragment
+        .children
+        .into_iter()
+        .find_map(|child| match child {
+            Child::Element(element)
+                if element.namespace == Namespace::SVG && element.tag_name == js_word!("svg") =>
+            {
+                Some(element)
+            }
+            _ => None,
+        })
+}
@@ -0,0 +1,49 @@
+use swc_atoms::js_word;
+use swc_html_ast::*;
+use swc_html_visit::{VisitMut, VisitMutWith};
+
+/// Adds `loading="lazy"` to `<img>` elements except the first
+/// `above_fold_count` ones in the document order.
+///
+/// Images which already have the `loading` attribute are counted, but left
+/// untouched.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LazyLoadImages {
+    pub above_fold_count: usize,
+}
+
+impl VisitMut for LazyLoadImages {
+    fn visit_mut_document(&mut self, n: &mut Document) {
+        n.visit_mut_children_with(&mut LazyLoader {
+            above_fold_count: self.above_fold_count,
+            image_count: 0,
+        });
+    }
+}
+
+struct LazyLoader {
+    above_fold_count: usize,
+    image_count: usize,
+}
+
+impl VisitMut for LazyLoader {
+    fn visit_mut_element(&mut self, n: &mut Element) {
+        if n.namespace != Namespace::HTML || n.tag_name != js_word!("img") {
+            n.visit_mut_children_with(self);
+
+            return;
+        }
+
+        self.image_count += 1;
+
+        if self.image_count <= self.above_fold_count {
+            return;
+        }
+
+        if n.has_attribute("loading") {
+            return;
+        }
+
+        n.set_attribute("loading", Some("lazy"));
+    }
+}
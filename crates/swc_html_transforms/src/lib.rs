@@ -0,0 +1,36 @@
+#![deny(clippy::all)]
+
+pub use self::{
+    add_canonical_link::AddCanonicalLink, add_integrity::AddIntegrity, add_nonce::AddNonce,
+    base_url_resolver::BaseUrlResolver, data_attribute_mapper::DataAttributeMapper,
+    extract_picture_sources::ExtractPictureSources, extract_scripts::ExtractScripts,
+    extract_styles::ExtractStyles, fragment_cache::hash_subtree, inject_head::InjectHead,
+    inject_meta_charset::InjectMetaCharset, inline_critical_css::InlineCriticalCss,
+    inline_svg::InlineSvg, lazy_load_images::LazyLoadImages, link_form_owners::LinkFormOwners,
+    normalize_entities::NormalizeEntities, preload_scanner::PreloadScanner,
+    replace_deprecated_elements::ReplaceDeprecatedElements, rewrite_asset_urls::RewriteAssetUrls,
+    strip_comments::StripComments, validate_sandbox::ValidateSandbox,
+};
+
+pub mod add_canonical_link;
+pub mod add_integrity;
+pub mod add_nonce;
+pub mod base_url_resolver;
+pub mod data_attribute_mapper;
+pub mod extract_picture_sources;
+pub mod extract_scripts;
+pub mod extract_styles;
+pub mod fragment_cache;
+pub mod inject_head;
+pub mod inject_meta_charset;
+pub mod inline_critical_css;
+pub mod inline_svg;
+pub mod lazy_load_images;
+pub mod link_form_owners;
+pub mod normalize_entities;
+pub mod preload_scanner;
+pub mod replace_deprecated_elements;
+pub mod rewrite_asset_urls;
+pub mod strip_comments;
+pub(crate) mod util;
+pub mod validate_sandbox;
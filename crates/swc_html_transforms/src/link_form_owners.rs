@@ -0,0 +1,75 @@
+use ahash::AHashSet;
+use swc_atoms::js_word;
+use swc_html_ast::*;
+use swc_html_visit::{Visit, VisitMut, VisitMutWith, VisitWith};
+
+// https://html.spec.whatwg.org/multipage/forms.html#category-listed
+static LISTED_ELEMENTS: &[&str] = &[
+    "button", "fieldset", "input", "object", "output", "select", "textarea",
+];
+
+/// Sets `data-swc-form-id` on form-associated elements whose `form` attribute
+/// matches the `id` of a `<form>` in the document, i.e. `<input form="login">`
+/// with `<form id="login">` somewhere else.
+///
+/// Elements referencing a missing form are left untouched.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LinkFormOwners;
+
+impl VisitMut for LinkFormOwners {
+    fn visit_mut_document(&mut self, n: &mut Document) {
+        let mut collector = FormIdCollector::default();
+
+        n.visit_with(&mut collector);
+
+        if collector.ids.is_empty() {
+            return;
+        }
+
+        n.visit_mut_children_with(&mut FormOwnerLinker {
+            ids: &collector.ids,
+        });
+    }
+}
+
+#[derive(Default)]
+struct FormIdCollector {
+    ids: AHashSet<String>,
+}
+
+impl Visit for FormIdCollector {
+    fn visit_element(&mut self, n: &Element) {
+        n.visit_children_with(self);
+
+        if n.namespace != Namespace::HTML || n.tag_name != js_word!("form") {
+            return;
+        }
+
+        if let Some(id) = n.get_attribute("id") {
+            if !id.is_empty() {
+                self.ids.insert(id.into());
+            }
+        }
+    }
+}
+
+struct FormOwnerLinker<'a> {
+    ids: &'a AHashSet<String>,
+}
+
+impl VisitMut for FormOwnerLinker<'_> {
+    fn visit_mut_element(&mut self, n: &mut Element) {
+        n.visit_mut_children_with(self);
+
+        if n.namespace != Namespace::HTML || !LISTED_ELEMENTS.contains(&&*n.tag_name) {
+            return;
+        }
+
+        let form_id = match n.get_attribute("form") {
+            Some(form_id) if self.ids.contains(form_id) => form_id.to_string(),
+            _ => return,
+        };
+
+        n.set_attribute("data-swc-form-id", Some(&form_id));
+    }
+}
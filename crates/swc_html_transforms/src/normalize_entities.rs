@@ -0,0 +1,19 @@
+use swc_html_ast::*;
+use swc_html_visit::VisitMut;
+
+/// Removes the raw value of text nodes which contain character references
+/// (`&amp;`, `&nbsp;`, `&#65;`, ...), so only the decoded text in
+/// [Text::data] is left in the AST, i.e. for text extraction.
+///
+/// The code generator always prints [Text::data] and escapes it as needed, so
+/// this pass doesn't change the output.
+#[derive(Debug, Default)]
+pub struct NormalizeEntities;
+
+impl VisitMut for NormalizeEntities {
+    fn visit_mut_text(&mut self, n: &mut Text) {
+        if matches!(&n.raw, Some(raw) if **raw != *n.data) {
+            n.raw = None;
+        }
+    }
+}
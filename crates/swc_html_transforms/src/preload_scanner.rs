@@ -0,0 +1,143 @@
+use swc_atoms::{js_word, JsWord};
+use swc_html_ast::*;
+use swc_html_visit::{VisitMut, VisitMutWith};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceType {
+    /// `<script src="...">`
+    Script,
+    /// `<script type="module" src="...">`
+    ModuleScript,
+    /// `<link rel="stylesheet" href="...">`
+    Style,
+    /// `<img src="...">`
+    Image,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreloadHint {
+    pub url: JsWord,
+    pub resource_type: ResourceType,
+}
+
+impl PreloadHint {
+    /// The value of the `as` attribute, module scripts use
+    /// `rel="modulepreload"`, which doesn't need it.
+    pub fn as_value(&self) -> Option<&'static str> {
+        match self.resource_type {
+            ResourceType::Script => Some("script"),
+            ResourceType::ModuleScript => None,
+            ResourceType::Style => Some("style"),
+            ResourceType::Image => Some("image"),
+        }
+    }
+
+    /// Returns the hint as a value of the HTTP `Link` header, i.e.
+    /// `<app.js>; rel=preload; as=script`.
+    pub fn to_link_header(&self) -> String {
+        match self.as_value() {
+            Some(as_value) => format!("<{}>; rel=preload; as={}", self.url, as_value),
+            None => format!("<{}>; rel=modulepreload", self.url),
+        }
+    }
+}
+
+/// Collects render-blocking resources, i.e. external scripts, stylesheets
+/// and the first `above_fold_count` images in the document order, which are
+/// candidates for `<link rel="preload">` or HTTP `Link` headers.
+///
+/// Each URL is collected once, `data:` URLs, images with `loading="lazy"` and
+/// the content of `<template>` are ignored. The document itself is left
+/// untouched.
+#[derive(Debug)]
+pub struct PreloadScanner {
+    pub hints: Vec<PreloadHint>,
+    above_fold_count: usize,
+    image_count: usize,
+}
+
+impl PreloadScanner {
+    pub fn new(above_fold_count: usize) -> Self {
+        PreloadScanner {
+            hints: vec![],
+            above_fold_count,
+            image_count: 0,
+        }
+    }
+
+    fn add_hint(&mut self, url: &str, resource_type: ResourceType) {
+        if url.is_empty()
+            || url.starts_with("data:")
+            || self.hints.iter().any(|hint| &*hint.url == url)
+        {
+            return;
+        }
+
+        self.hints.push(PreloadHint {
+            url: url.into(),
+            resource_type,
+        });
+    }
+}
+
+impl VisitMut for PreloadScanner {
+    fn visit_mut_element(&mut self, n: &mut Element) {
+        if n.namespace != Namespace::HTML {
+            n.visit_mut_children_with(self);
+
+            return;
+        }
+
+        match n.tag_name {
+            js_word!("template") => return,
+            js_word!("script") => {
+                if let Some(src) = n.get_attribute("src") {
+                    let is_module = matches!(
+                        n.get_attribute("type"),
+                        Some(ty) if ty.eq_ignore_ascii_case("module")
+                    );
+
+                    self.add_hint(
+                        src,
+                        if is_module {
+                            ResourceType::ModuleScript
+                        } else {
+                            ResourceType::Script
+                        },
+                    );
+                }
+            }
+            js_word!("link") => {
+                let is_stylesheet = matches!(
+                    n.get_attribute("rel"),
+                    Some(rel) if rel
+                        .split_ascii_whitespace()
+                        .any(|rel| rel.eq_ignore_ascii_case("stylesheet"))
+                );
+
+                if is_stylesheet {
+                    if let Some(href) = n.get_attribute("href") {
+                        self.add_hint(href, ResourceType::Style);
+                    }
+                }
+            }
+            js_word!("img") => {
+                self.image_count += 1;
+
+                let is_lazy = matches!(
+                    n.get_attribute("loading"),
+                    Some(loading) if loading.eq_ignore_ascii_case("lazy")
+                );
+
+                if self.image_count <= self.above_fold_count && !is_lazy {
+                    if let Some(src) = n.get_attribute("src") {
+                        self.add_hint(src, ResourceType::Image);
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        n.visit_mut_children_with(self);
+    }
+}
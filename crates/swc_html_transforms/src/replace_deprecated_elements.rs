@@ -0,0 +1,90 @@
+use ahash::AHashMap;
+use swc_atoms::JsWord;
+use swc_html_ast::*;
+use swc_html_visit::{VisitMut, VisitMutWith};
+
+// https://html.spec.whatwg.org/multipage/obsolete.html#non-conforming-features
+static DEFAULT_REPLACEMENTS: &[(&str, &str)] = &[
+    ("acronym", "abbr"),
+    ("big", "span"),
+    ("blink", "span"),
+    ("center", "div"),
+    ("dir", "ul"),
+    ("font", "span"),
+    ("listing", "pre"),
+    ("marquee", "div"),
+    ("nobr", "span"),
+    ("plaintext", "pre"),
+    ("rb", "span"),
+    ("rtc", "span"),
+    ("strike", "s"),
+    ("tt", "code"),
+    ("xmp", "pre"),
+];
+
+/// Renames obsolete HTML elements, i.e. `<center>` or `<font>`, using `map`
+/// from the obsolete tag name to the replacement.
+///
+/// Presentational attributes which only make sense on the obsolete element,
+/// i.e. `face` on `<font>`, are removed from renamed elements.
+///
+/// The default map only contains elements with a close modern equivalent,
+/// elements like `<frameset>` or `<applet>` have to be added explicitly.
+#[derive(Debug, Clone)]
+pub struct ReplaceDeprecatedElements {
+    pub map: AHashMap<JsWord, JsWord>,
+}
+
+impl Default for ReplaceDeprecatedElements {
+    fn default() -> Self {
+        ReplaceDeprecatedElements {
+            map: DEFAULT_REPLACEMENTS
+                .iter()
+                .map(|(from, to)| ((*from).into(), (*to).into()))
+                .collect(),
+        }
+    }
+}
+
+impl VisitMut for ReplaceDeprecatedElements {
+    fn visit_mut_element(&mut self, n: &mut Element) {
+        n.visit_mut_children_with(self);
+
+        if n.namespace != Namespace::HTML {
+            return;
+        }
+
+        let tag_name = match self.map.get(&n.tag_name) {
+            Some(tag_name) => tag_name.clone(),
+            None => return,
+        };
+
+        let obsolete_attributes = get_obsolete_attributes(&n.tag_name);
+
+        n.attributes.retain(|attribute| {
+            attribute.namespace.is_some() || !obsolete_attributes.contains(&&*attribute.name)
+        });
+        n.tag_name = tag_name;
+    }
+}
+
+fn get_obsolete_attributes(tag_name: &str) -> &'static [&'static str] {
+    match tag_name {
+        "dir" => &["compact"],
+        "font" => &["color", "face", "size"],
+        "marquee" => &[
+            "behavior",
+            "bgcolor",
+            "direction",
+            "height",
+            "hspace",
+            "loop",
+            "scrollamount",
+            "scrolldelay",
+            "truespeed",
+            "vspace",
+            "width",
+        ],
+        _ => &[],
+    }
+}
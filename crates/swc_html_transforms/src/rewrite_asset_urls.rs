@@ -0,0 +1,116 @@
+use swc_atoms::js_word;
+use swc_html_ast::*;
+use swc_html_visit::{VisitMut, VisitMutWith};
+
+use crate::base_url_resolver::map_srcset;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssetKind {
+    /// `<script src="...">` and `<link rel="preload" as="script">`
+    Script,
+    /// `<link rel="stylesheet">` and `<link rel="preload" as="style">`
+    Stylesheet,
+    /// `<img>`, `<picture>` sources, `<input type="image">`, icons, `poster`
+    /// and `background` attributes
+    Image,
+    /// `<link rel="preload" as="font">`
+    Font,
+    /// `<iframe src="...">` and `<frame src="...">`
+    Frame,
+    /// Everything else, i.e. `<a href="...">`, `<form action="...">` or
+    /// `<object data="...">`
+    Other,
+}
+
+/// Rewrites URLs in `src`, `href`, `srcset`, `poster`, `data`, `action` and
+/// `background` attributes, i.e. to add content hashes or a CDN prefix.
+///
+/// `transform` is called with every URL and the kind of the asset, returning
+/// `None` leaves the URL unchanged. URLs in `srcset` are passed one by one.
+pub struct RewriteAssetUrls {
+    pub transform: Box<dyn Fn(&str, AssetKind) -> Option<String>>,
+}
+
+impl VisitMut for RewriteAssetUrls {
+    fn visit_mut_element(&mut self, n: &mut Element) {
+        n.visit_mut_children_with(self);
+
+        // The `href` attribute of `<base>` is not an asset
+        if n.namespace != Namespace::HTML || n.tag_name == js_word!("base") {
+            return;
+        }
+
+        let kind = get_asset_kind(n);
+        let is_object = n.tag_name == js_word!("object");
+
+        for attribute in n.attributes.iter_mut() {
+            if attribute.namespace.is_some() {
+                continue;
+            }
+
+            let value = match &attribute.value {
+                Some(value) => value,
+                _ => continue,
+            };
+
+            let transformed = match &*attribute.name {
+                "src" | "href" | "action" => (self.transform)(value.trim(), kind),
+                "data" if is_object => (self.transform)(value.trim(), kind),
+                "poster" | "background" => (self.transform)(value.trim(), AssetKind::Image),
+                "srcset" => map_srcset(value, |url| (self.transform)(url, kind)),
+                _ => continue,
+            };
+
+            if let Some(transformed) = transformed {
+                attribute.value = Some(transformed.into());
+                attribute.raw_value = None;
+            }
+        }
+    }
+}
+
+fn get_asset_kind(n: &Element) -> AssetKind {
+    match &*n.tag_name {
+        "script" => AssetKind::Script,
+        "img" => AssetKind::Image,
+        // `<source>` in `<audio>` and `<video>` can't have `srcset`, so it is a source of
+        // `<picture>`
+        "source" if n.has_attribute("srcset") => AssetKind::Image,
+        "input" => match n.get_attribute("type") {
+            Some(value) if value.trim().eq_ignore_ascii_case("image") => AssetKind::Image,
+            _ => AssetKind::Other,
+        },
+        "iframe" | "frame" => AssetKind::Frame,
+        "link" => {
+            let has_rel = |expected: &str| match n.get_attribute("rel") {
+                Some(value) => value
+                    .split_ascii_whitespace()
+                    .any(|rel| rel.eq_ignore_ascii_case(expected)),
+                None => false,
+            };
+
+            if has_rel("stylesheet") {
+                AssetKind::Stylesheet
+            } else if has_rel("icon") || has_rel("apple-touch-icon") {
+                AssetKind::Image
+            } else if has_rel("preload") || has_rel("modulepreload") {
+                match n
+                    .get_attribute("as")
+                    .map(|value| value.trim().to_ascii_lowercase())
+                    .as_deref()
+                {
+                    Some("style") => AssetKind::Stylesheet,
+                    Some("image") => AssetKind::Image,
+                    Some("font") => AssetKind::Font,
+                    Some("script") => AssetKind::Script,
+                    // `modulepreload` is `script` by default
+                    None if has_rel("modulepreload") => AssetKind::Script,
+                    _ => AssetKind::Other,
+                }
+            } else {
+                AssetKind::Other
+            }
+        }
+        _ => AssetKind::Other,
+    }
+}
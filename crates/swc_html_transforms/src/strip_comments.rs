@@ -0,0 +1,70 @@
+use regex::Regex;
+use swc_html_ast::*;
+use swc_html_visit::{VisitMut, VisitMutWith};
+
+/// Removes comments from the document.
+///
+/// `retain` is called with the data of every comment and should return `true`
+/// to keep the comment, i.e. for IE conditional comments, KnockoutJS
+/// bindings or SSI includes.
+pub struct StripComments {
+    pub retain: Box<dyn Fn(&str) -> bool>,
+}
+
+impl StripComments {
+    /// Removes all comments.
+    pub fn all() -> Self {
+        StripComments {
+            retain: Box::new(|_| false),
+        }
+    }
+
+    /// Removes all comments except IE conditional comments, i.e.
+    /// `<!--[if IE]>...<![endif]-->` and `<!--<![endif]-->`.
+    pub fn keep_ie_conditionals() -> Self {
+        StripComments {
+            retain: Box::new(is_conditional_comment),
+        }
+    }
+
+    /// Removes all comments except the ones matching `regex`.
+    pub fn keep_pattern(regex: Regex) -> Self {
+        StripComments {
+            retain: Box::new(move |data| regex.is_match(data)),
+        }
+    }
+
+    fn strip(&self, children: &mut Vec<Child>) {
+        children.retain(|child| match child {
+            Child::Comment(comment) => (self.retain)(&comment.data),
+            _ => true,
+        });
+    }
+}
+
+impl VisitMut for StripComments {
+    fn visit_mut_document(&mut self, n: &mut Document) {
+        self.strip(&mut n.children);
+
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_document_fragment(&mut self, n: &mut DocumentFragment) {
+        self.strip(&mut n.children);
+
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_element(&mut self, n: &mut Element) {
+        self.strip(&mut n.children);
+
+        n.visit_mut_children_with(self);
+    }
+}
+
+fn is_conditional_comment(data: &str) -> bool {
+    let trimmed = data.trim_start();
+
+    (trimmed.starts_with("[if") && trimmed[3..].starts_with(char::is_whitespace))
+        || data.contains("[endif]")
+}
@@ -0,0 +1,29 @@
+use swc_atoms::js_word;
+use swc_html_ast::*;
+
+/// Returns the `<head>` element of `document`, which is a child of the
+/// `<html>` element after parsing.
+pub(crate) fn get_head_mut(document: &mut Document) -> Option<&mut Element> {
+    document
+        .children
+        .iter_mut()
+        .find_map(|child| match child {
+            Child::Element(element)
+                if element.namespace == Namespace::HTML && element.tag_name == js_word!("html") =>
+            {
+                Some(element)
+            }
+            _ => None,
+        })
+        .and_then(|html| {
+            html.children.iter_mut().find_map(|child| match child {
+                Child::Element(element)
+                    if element.namespace == Namespace::HTML
+                        && element.tag_name == js_word!("head") =>
+                {
+                    Some(element)
+                }
+                _ => None,
+            })
+        })
+}
@@ -0,0 +1,67 @@
+use swc_atoms::js_word;
+use swc_common::errors::HANDLER;
+use swc_html_ast::*;
+use swc_html_visit::{VisitMut, VisitMutWith};
+
+// https://html.spec.whatwg.org/multipage/iframe-embed-object.html#attr-iframe-sandbox
+static SANDBOX_KEYWORDS: &[&str] = &[
+    "allow-downloads",
+    "allow-forms",
+    "allow-modals",
+    "allow-orientation-lock",
+    "allow-pointer-lock",
+    "allow-popups",
+    "allow-popups-to-escape-sandbox",
+    "allow-presentation",
+    "allow-same-origin",
+    "allow-scripts",
+    "allow-top-navigation",
+    "allow-top-navigation-by-user-activation",
+    "allow-top-navigation-to-custom-protocols",
+];
+
+/// Reports unknown keywords in the `sandbox` attribute of `<iframe>` elements
+/// as warnings, i.e. typos like `allow-scipts`.
+///
+/// Diagnostics are emitted to [HANDLER], the document itself is left
+/// untouched.
+#[derive(Debug, Default)]
+pub struct ValidateSandbox;
+
+impl VisitMut for ValidateSandbox {
+    fn visit_mut_element(&mut self, n: &mut Element) {
+        n.visit_mut_children_with(self);
+
+        if n.namespace != Namespace::HTML || n.tag_name != js_word!("iframe") {
+            return;
+        }
+
+        let attribute = match n.get_attribute_node("sandbox") {
+            Some(attribute) => attribute,
+            None => return,
+        };
+
+        let value = match &attribute.value {
+            Some(value) => value,
+            None => return,
+        };
+
+        for keyword in value.split_ascii_whitespace() {
+            if SANDBOX_KEYWORDS
+                .iter()
+                .any(|known| known.eq_ignore_ascii_case(keyword))
+            {
+                continue;
+            }
+
+            HANDLER.with(|handler| {
+                handler
+                    .struct_span_warn(
+                        attribute.span,
+                        &format!("Unexpected unknown sandbox keyword '{}'.", keyword),
+                    )
+                    .emit()
+            });
+        }
+    }
+}
@@ -0,0 +1,734 @@
+#![deny(warnings)]
+
+use std::path::PathBuf;
+
+use regex::Regex;
+use swc_common::{errors::HANDLER, BytePos, FileName, Span, DUMMY_SP};
+use swc_html_ast::{Attribute, Child, Document, Element, Namespace, Text};
+use swc_html_codegen::{
+    writer::basic::{BasicHtmlWriter, BasicHtmlWriterConfig},
+    CodeGenerator, CodegenConfig, Emit,
+};
+use swc_html_parser::parse_file_as_document;
+use swc_html_transforms::{
+    add_canonical_link::AddCanonicalLink,
+    add_integrity::AddIntegrity,
+    add_nonce::AddNonce,
+    base_url_resolver::BaseUrlResolver,
+    data_attribute_mapper::DataAttributeMapper,
+    extract_picture_sources::{ExtractPictureSources, ExtractedPicture, PictureSource},
+    extract_scripts::{ExtractScripts, ExtractedScript},
+    extract_styles::{ExtractStyles, ExtractedStyle},
+    hash_subtree,
+    inject_head::{InjectHead, InjectPosition},
+    inject_meta_charset::{InjectMetaCharset, InjectMetaCharsetConfig, MetaPosition},
+    inline_critical_css::InlineCriticalCss,
+    inline_svg::InlineSvg,
+    lazy_load_images::LazyLoadImages,
+    link_form_owners::LinkFormOwners,
+    normalize_entities::NormalizeEntities,
+    preload_scanner::{PreloadHint, PreloadScanner, ResourceType},
+    replace_deprecated_elements::ReplaceDeprecatedElements,
+    rewrite_asset_urls::{AssetKind, RewriteAssetUrls},
+    strip_comments::StripComments,
+    validate_sandbox::ValidateSandbox,
+};
+use swc_html_visit::{VisitMut, VisitMutWith};
+use testing::NormalizedOutput;
+
+fn transform<V>(input: PathBuf, mut pass: V)
+where
+    V: VisitMut,
+{
+    let output = input.parent().unwrap().join("output.html");
+
+    testing::run_test(false, |cm, handler| {
+        let fm = cm.load_file(&input).unwrap();
+
+        let mut errors = vec![];
+        let result: Result<Document, _> =
+            parse_file_as_document(&fm, Default::default(), &mut errors);
+
+        for err in errors {
+            err.to_diagnostics(handler).emit();
+        }
+
+        if handler.has_errors() {
+            return Err(());
+        }
+
+        let mut document = result.unwrap();
+
+        document.visit_mut_with(&mut pass);
+
+        let mut html_str = String::new();
+        {
+            let wr = BasicHtmlWriter::new(&mut html_str, None, BasicHtmlWriterConfig::default());
+            let mut gen = CodeGenerator::new(wr, CodegenConfig::default());
+
+            gen.emit(&document).unwrap();
+        }
+
+        NormalizedOutput::new_raw(html_str)
+            .compare_to_file(&output)
+            .unwrap();
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[testing::fixture("tests/fixture/add_canonical_link/insert/**/input.html")]
+fn test_add_canonical_link_insert(input: PathBuf) {
+    transform(
+        input,
+        AddCanonicalLink {
+            canonical_url: "https://example.com/page".into(),
+            replace_existing: false,
+        },
+    );
+}
+
+#[testing::fixture("tests/fixture/add_canonical_link/replace/**/input.html")]
+fn test_add_canonical_link_replace(input: PathBuf) {
+    transform(
+        input,
+        AddCanonicalLink {
+            canonical_url: "https://example.com/page".into(),
+            replace_existing: true,
+        },
+    );
+}
+
+#[testing::fixture("tests/fixture/inline_critical_css/**/input.html")]
+fn test_inline_critical_css(input: PathBuf) {
+    transform(
+        input,
+        InlineCriticalCss {
+            critical_css: "body{margin:0}".into(),
+            should_defer: Box::new(|href| !href.starts_with("https:")),
+        },
+    );
+}
+
+#[testing::fixture("tests/fixture/replace_deprecated_elements/**/input.html")]
+fn test_replace_deprecated_elements(input: PathBuf) {
+    transform(input, ReplaceDeprecatedElements::default());
+}
+
+#[testing::fixture("tests/fixture/link_form_owners/**/input.html")]
+fn test_link_form_owners(input: PathBuf) {
+    transform(input, LinkFormOwners);
+}
+
+#[testing::fixture("tests/fixture/base_url_resolver/**/input.html")]
+fn test_base_url_resolver(input: PathBuf) {
+    transform(input, BaseUrlResolver);
+}
+
+#[testing::fixture("tests/fixture/inject_meta_charset/first/**/input.html")]
+fn test_inject_meta_charset_first(input: PathBuf) {
+    transform(input, InjectMetaCharset::default());
+}
+
+#[testing::fixture("tests/fixture/inject_meta_charset/after_title/**/input.html")]
+fn test_inject_meta_charset_after_title(input: PathBuf) {
+    transform(
+        input,
+        InjectMetaCharset {
+            config: InjectMetaCharsetConfig {
+                position: MetaPosition::AfterTitle,
+                ..Default::default()
+            },
+        },
+    );
+}
+
+#[testing::fixture("tests/fixture/add_integrity/**/input.html")]
+fn test_add_integrity(input: PathBuf) {
+    transform(
+        input,
+        AddIntegrity {
+            compute_hash: Box::new(|url| format!("sha384-{}", url)),
+        },
+    );
+}
+
+#[testing::fixture("tests/fixture/add_nonce/default/**/input.html")]
+fn test_add_nonce(input: PathBuf) {
+    transform(
+        input,
+        AddNonce {
+            nonce: "r4nd0m".into(),
+            ..Default::default()
+        },
+    );
+}
+
+#[testing::fixture("tests/fixture/add_nonce/include_external/**/input.html")]
+fn test_add_nonce_include_external(input: PathBuf) {
+    transform(
+        input,
+        AddNonce {
+            nonce: "r4nd0m".into(),
+            include_external: true,
+        },
+    );
+}
+
+#[testing::fixture("tests/fixture/lazy_load_images/**/input.html")]
+fn test_lazy_load_images(input: PathBuf) {
+    transform(
+        input,
+        LazyLoadImages {
+            above_fold_count: 2,
+        },
+    );
+}
+
+#[testing::fixture("tests/fixture/data_attribute_mapper/**/input.html")]
+fn test_data_attribute_mapper(input: PathBuf) {
+    transform(
+        input,
+        DataAttributeMapper {
+            map: Box::new(|name, value| {
+                if name.starts_with('*') || name.starts_with("v-") {
+                    return None;
+                }
+
+                if name == "data-ng-src" {
+                    return Some(("src".into(), value.map(Into::into)));
+                }
+
+                Some((name.into(), value.map(Into::into)))
+            }),
+        },
+    );
+}
+
+const ICON_SVG: &str = concat!(
+    "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+    "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 16 16\">",
+    "<path d=\"M0 0h16v16H0z\"/>",
+    "</svg>\n",
+);
+
+#[testing::fixture("tests/fixture/inline_svg/**/input.html")]
+fn test_inline_svg(input: PathBuf) {
+    transform(
+        input,
+        InlineSvg {
+            resolver: Box::new(|url| match url {
+                "icon.svg" => Some(ICON_SVG.into()),
+                _ => None,
+            }),
+        },
+    );
+}
+
+#[testing::fixture("tests/fixture/rewrite_asset_urls/**/input.html")]
+fn test_rewrite_asset_urls(input: PathBuf) {
+    transform(
+        input,
+        RewriteAssetUrls {
+            transform: Box::new(|url, kind| {
+                if url.starts_with("https:") || url.starts_with("data:") {
+                    return None;
+                }
+
+                let directory = match kind {
+                    AssetKind::Script => "js",
+                    AssetKind::Stylesheet => "css",
+                    AssetKind::Image => "img",
+                    AssetKind::Font => "fonts",
+                    AssetKind::Frame => "frames",
+                    AssetKind::Other => return None,
+                };
+
+                Some(format!("https://cdn.example.com/{}{}", directory, url))
+            }),
+        },
+    );
+}
+
+#[testing::fixture("tests/fixture/strip_comments/all/**/input.html")]
+fn test_strip_comments_all(input: PathBuf) {
+    transform(input, StripComments::all());
+}
+
+#[testing::fixture("tests/fixture/strip_comments/keep_ie_conditionals/**/input.html")]
+fn test_strip_comments_keep_ie_conditionals(input: PathBuf) {
+    transform(input, StripComments::keep_ie_conditionals());
+}
+
+#[testing::fixture("tests/fixture/strip_comments/keep_pattern/**/input.html")]
+fn test_strip_comments_keep_pattern(input: PathBuf) {
+    transform(
+        input,
+        StripComments::keep_pattern(Regex::new(r"^\s*(/?ko\b|#)").unwrap()),
+    );
+}
+
+#[testing::fixture("tests/fixture/extract_styles/**/input.html")]
+fn test_extract_styles(input: PathBuf) {
+    let mut index = 0;
+
+    transform(
+        input,
+        ExtractStyles {
+            href: Some(Box::new(move |_| {
+                index += 1;
+
+                Some(format!("style-{}.css", index).into())
+            })),
+            ..Default::default()
+        },
+    );
+}
+
+fn create_style(id: &str, css: &str) -> Child {
+    Child::Element(Element {
+        span: DUMMY_SP,
+        tag_name: "style".into(),
+        namespace: Namespace::HTML,
+        attributes: vec![Attribute {
+            span: DUMMY_SP,
+            namespace: None,
+            prefix: None,
+            name: "id".into(),
+            raw_name: None,
+            value: Some(id.into()),
+            raw_value: None,
+        }],
+        children: vec![Child::Text(Text {
+            span: DUMMY_SP,
+            data: css.into(),
+            raw: None,
+        })],
+        content: None,
+        is_self_closing: false,
+    })
+}
+
+#[testing::fixture("tests/fixture/inject_head/append/**/input.html")]
+fn test_inject_head_append(input: PathBuf) {
+    transform(
+        input,
+        InjectHead {
+            children: vec![
+                create_style("critical", "a{color:red}"),
+                create_style("theme", "b{color:blue}"),
+            ],
+            position: InjectPosition::Append,
+        },
+    );
+}
+
+#[testing::fixture("tests/fixture/inject_head/prepend/**/input.html")]
+fn test_inject_head_prepend(input: PathBuf) {
+    transform(
+        input,
+        InjectHead {
+            children: vec![
+                create_style("critical", "a{color:red}"),
+                create_style("theme", "b{color:blue}"),
+            ],
+            position: InjectPosition::Prepend,
+        },
+    );
+}
+
+#[test]
+fn extract_scripts() {
+    testing::run_test(false, |cm, _| {
+        let src = concat!(
+            "<!doctype html><html><head>",
+            "<script type=\"module\">import \"a\";</script>",
+            "<script src=\"b.js\"></script>",
+            "</head><body>",
+            "<svg><script>svg()</script></svg>",
+            "<script>c()</script>",
+            "</body></html>",
+        );
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+
+        let mut errors = vec![];
+        let mut document: Document =
+            parse_file_as_document(&fm, Default::default(), &mut errors).unwrap();
+
+        assert!(errors.is_empty());
+
+        let mut pass = ExtractScripts::default();
+
+        document.visit_mut_with(&mut pass);
+
+        let pos_of = |text: &str| Some(fm.start_pos + BytePos(src.find(text).unwrap() as u32));
+
+        assert_eq!(
+            pass.scripts,
+            vec![
+                ExtractedScript {
+                    text: "import \"a\";".into(),
+                    src: None,
+                    ty: Some("module".into()),
+                    text_pos: pos_of("import"),
+                },
+                ExtractedScript {
+                    text: "".into(),
+                    src: Some("b.js".into()),
+                    ty: None,
+                    text_pos: None,
+                },
+                ExtractedScript {
+                    text: "c()".into(),
+                    src: None,
+                    ty: None,
+                    text_pos: pos_of("c()"),
+                },
+            ]
+        );
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn extract_styles() {
+    testing::run_test(false, |cm, _| {
+        let src = concat!(
+            "<!doctype html><html><head>",
+            "<style media=\"screen\">a{color:red}</style>",
+            "<style></style>",
+            "</head><body>",
+            "<svg><style>circle{fill:red}</style></svg>",
+            "<style>b{color:blue}</style>",
+            "</body></html>",
+        );
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+
+        let mut errors = vec![];
+        let mut document: Document =
+            parse_file_as_document(&fm, Default::default(), &mut errors).unwrap();
+
+        assert!(errors.is_empty());
+
+        let mut pass = ExtractStyles::default();
+
+        document.visit_mut_with(&mut pass);
+
+        let pos_of = |text: &str| Some(fm.start_pos + BytePos(src.find(text).unwrap() as u32));
+
+        assert_eq!(
+            pass.styles,
+            vec![
+                ExtractedStyle {
+                    text: "a{color:red}".into(),
+                    media: Some("screen".into()),
+                    text_pos: pos_of("a{color:red}"),
+                },
+                ExtractedStyle {
+                    text: "".into(),
+                    media: None,
+                    text_pos: None,
+                },
+                ExtractedStyle {
+                    text: "b{color:blue}".into(),
+                    media: None,
+                    text_pos: pos_of("b{color:blue}"),
+                },
+            ]
+        );
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn hash_subtree_ignores_spans() {
+    testing::run_test(false, |cm, _| {
+        let parse = |src: &str| {
+            let fm = cm.new_source_file(FileName::Anon, src.into());
+
+            let mut errors = vec![];
+            let document: Document =
+                parse_file_as_document(&fm, Default::default(), &mut errors).unwrap();
+
+            assert!(errors.is_empty());
+
+            document
+        };
+
+        let src = concat!(
+            "<!doctype html><html><body><div class=\"a\" id=\"b\">a&amp;b",
+            "<!-- comment --></div></body></html>"
+        );
+        // Sources are placed one after another in the source map, so spans are
+        // different
+        let first = parse(src);
+        let second = parse(src);
+
+        assert_ne!(first, second);
+        assert_eq!(
+            hash_subtree(&first.children[1]),
+            hash_subtree(&second.children[1])
+        );
+
+        // Only raw values of the attribute and the text are different
+        let different_raw = parse(concat!(
+            "<!doctype html><html><body><div class='a' id=b>a&#38;b",
+            "<!-- comment --></div></body></html>"
+        ));
+
+        assert_eq!(
+            hash_subtree(&first.children[1]),
+            hash_subtree(&different_raw.children[1])
+        );
+
+        let reordered = parse(concat!(
+            "<!doctype html><html><body><div id=\"b\" class=\"a\">a&amp;b",
+            "<!-- comment --></div></body></html>"
+        ));
+
+        assert_ne!(
+            hash_subtree(&first.children[1]),
+            hash_subtree(&reordered.children[1])
+        );
+
+        let changed_text = parse(concat!(
+            "<!doctype html><html><body><div class=\"a\" id=\"b\">other",
+            "<!-- comment --></div></body></html>"
+        ));
+
+        assert_ne!(
+            hash_subtree(&first.children[1]),
+            hash_subtree(&changed_text.children[1])
+        );
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn hash_subtree_is_stable() {
+    // The hash is used as a persistent cache key, so it must not change between
+    // builds
+    let text = Child::Text(Text {
+        span: DUMMY_SP,
+        data: "a".into(),
+        raw: None,
+    });
+
+    assert_eq!(hash_subtree(&text), 0x07b2_d575_92fc_095f);
+}
+
+#[derive(Default)]
+struct TextCollector {
+    texts: Vec<(String, Option<String>)>,
+}
+
+impl VisitMut for TextCollector {
+    fn visit_mut_text(&mut self, n: &mut Text) {
+        self.texts.push((
+            n.data.to_string(),
+            n.raw.as_ref().map(|raw| raw.to_string()),
+        ));
+    }
+}
+
+#[test]
+fn normalize_entities() {
+    testing::run_test(false, |cm, _| {
+        let src = "<!doctype html><html><body><p>a &amp; b&nbsp;&lt;c&gt; &#x41;&#66;</p><p>plain \
+                   text</p></body></html>";
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+
+        let mut errors = vec![];
+        let mut document: Document =
+            parse_file_as_document(&fm, Default::default(), &mut errors).unwrap();
+
+        assert!(errors.is_empty());
+
+        document.visit_mut_with(&mut NormalizeEntities);
+
+        let mut collector = TextCollector::default();
+
+        document.visit_mut_with(&mut collector);
+
+        assert_eq!(
+            collector.texts,
+            vec![
+                ("a & b\u{a0}<c> AB".into(), None),
+                ("plain text".into(), Some("plain text".into())),
+            ]
+        );
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn extract_picture_sources() {
+    testing::run_test(false, |cm, _| {
+        let src = concat!(
+            "<!doctype html><html><body>",
+            "<picture>",
+            "<source srcset=\"a.avif\" type=\"image/avif\">",
+            "<source srcset=\"a-wide.webp 2x, a.webp\" media=\"(min-width: 800px)\" ",
+            "sizes=\"50vw\" type=\"image/webp\">",
+            "<img src=\"a.jpg\" alt=\"\">",
+            "</picture>",
+            "<source srcset=\"ignored.webp\">",
+            "<picture><img src=\"b.jpg\" alt=\"\"></picture>",
+            "</body></html>",
+        );
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+
+        let mut errors = vec![];
+        let mut document: Document =
+            parse_file_as_document(&fm, Default::default(), &mut errors).unwrap();
+
+        assert!(errors.is_empty());
+
+        let mut pass = ExtractPictureSources::default();
+
+        document.visit_mut_with(&mut pass);
+
+        let span_of = |start: &str, end: &str| {
+            let lo = src.find(start).unwrap();
+            let hi = lo + src[lo..].find(end).unwrap() + end.len();
+
+            Span::new(
+                fm.start_pos + BytePos(lo as u32),
+                fm.start_pos + BytePos(hi as u32),
+                Default::default(),
+            )
+        };
+
+        assert_eq!(
+            pass.pictures,
+            vec![
+                ExtractedPicture {
+                    span: span_of("<picture><source", "</picture>"),
+                    sources: vec![
+                        PictureSource {
+                            srcset: Some("a.avif".into()),
+                            media: None,
+                            ty: Some("image/avif".into()),
+                            sizes: None,
+                        },
+                        PictureSource {
+                            srcset: Some("a-wide.webp 2x, a.webp".into()),
+                            media: Some("(min-width: 800px)".into()),
+                            ty: Some("image/webp".into()),
+                            sizes: Some("50vw".into()),
+                        },
+                    ],
+                },
+                ExtractedPicture {
+                    span: span_of("<picture><img src=\"b.jpg\"", "</picture>"),
+                    sources: vec![],
+                },
+            ]
+        );
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn preload_scanner() {
+    testing::run_test(false, |cm, _| {
+        let src = concat!(
+            "<!doctype html><html><head>",
+            "<link rel=\"stylesheet\" href=\"style.css\">",
+            "<link rel=\"icon\" href=\"favicon.ico\">",
+            "<script src=\"app.js\"></script>",
+            "<script type=\"module\" src=\"main.mjs\"></script>",
+            "<script>console.log(1);</script>",
+            "</head><body>",
+            "<img src=\"hero.jpg\" alt=\"\">",
+            "<img src=\"data:image/gif;base64,R0lGODlhAQABAAAAACw=\" alt=\"\">",
+            "<img src=\"below.jpg\" alt=\"\">",
+            "<template><script src=\"template.js\"></script></template>",
+            "<script src=\"app.js\"></script>",
+            "</body></html>",
+        );
+        let fm = cm.new_source_file(FileName::Anon, src.into());
+
+        let mut errors = vec![];
+        let mut document: Document =
+            parse_file_as_document(&fm, Default::default(), &mut errors).unwrap();
+
+        assert!(errors.is_empty());
+
+        let mut pass = PreloadScanner::new(2);
+
+        document.visit_mut_with(&mut pass);
+
+        assert_eq!(
+            pass.hints,
+            vec![
+                PreloadHint {
+                    url: "style.css".into(),
+                    resource_type: ResourceType::Style,
+                },
+                PreloadHint {
+                    url: "app.js".into(),
+                    resource_type: ResourceType::Script,
+                },
+                PreloadHint {
+                    url: "main.mjs".into(),
+                    resource_type: ResourceType::ModuleScript,
+                },
+                PreloadHint {
+                    url: "hero.jpg".into(),
+                    resource_type: ResourceType::Image,
+                },
+            ]
+        );
+        assert_eq!(
+            pass.hints
+                .iter()
+                .map(PreloadHint::to_link_header)
+                .collect::<Vec<_>>(),
+            vec![
+                "<style.css>; rel=preload; as=style",
+                "<app.js>; rel=preload; as=script",
+                "<main.mjs>; rel=modulepreload",
+                "<hero.jpg>; rel=preload; as=image",
+            ]
+        );
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[testing::fixture("tests/fixture/validate_sandbox/**/input.html")]
+fn test_validate_sandbox(input: PathBuf) {
+    let stderr_path = input.parent().unwrap().join("output.stderr");
+
+    let stderr = testing::run_test2(false, |cm, handler| -> Result<(), _> {
+        let fm = cm.load_file(&input).unwrap();
+
+        let mut errors = vec![];
+        let mut document: Document =
+            parse_file_as_document(&fm, Default::default(), &mut errors).unwrap();
+
+        assert!(errors.is_empty());
+
+        HANDLER.set(&handler, || {
+            document.visit_mut_with(&mut ValidateSandbox);
+        });
+
+        Err(())
+    })
+    .unwrap_err();
+
+    stderr.compare_to_file(&stderr_path).unwrap();
+}